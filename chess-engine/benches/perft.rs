@@ -0,0 +1,45 @@
+use chess_engine::bitboard::TABLES;
+use chess_engine::fen::START_FEN;
+use chess_engine::state::State;
+use std::time::Instant;
+
+// Run With `cargo bench --bench perft`
+const POSITIONS: [(&str, &str, u32, u64); 3] = [
+    ("start position", START_FEN, 5, 4865609),
+    (
+        "kiwipete",
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        4,
+        4085603,
+    ),
+    (
+        "position 3",
+        "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+        5,
+        674624,
+    ),
+];
+
+fn main() {
+    // Build the Attack Tables Up Front so They Are Not Timed
+    once_cell::sync::Lazy::force(&TABLES);
+
+    for (name, fen, depth, expected) in POSITIONS {
+        let mut state = State::from_fen(fen).unwrap();
+
+        let start = Instant::now();
+        let nodes = state.perft(depth);
+        let elapsed = start.elapsed();
+
+        assert_eq!(nodes, expected, "{} depth {}", name, depth);
+
+        println!(
+            "{:<16} depth {}  {:>10} nodes  {:>8.3}s  {:>12.0} nps",
+            name,
+            depth,
+            nodes,
+            elapsed.as_secs_f64(),
+            nodes as f64 / elapsed.as_secs_f64()
+        );
+    }
+}
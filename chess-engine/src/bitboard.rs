@@ -0,0 +1,298 @@
+use super::square::Square;
+use once_cell::sync::Lazy;
+
+pub type Bitboard = u64;
+
+pub const RANK_1: Bitboard = 0xFF;
+pub const RANK_8: Bitboard = 0xFF << 56;
+pub const FILE_A: Bitboard = 0x0101_0101_0101_0101;
+pub const FILE_H: Bitboard = FILE_A << 7;
+
+pub fn bit(square: Square) -> Bitboard {
+    1 << square.index()
+}
+
+pub fn lsb(bitboard: Bitboard) -> Square {
+    Square::from_index(bitboard.trailing_zeros() as usize)
+}
+
+pub fn squares(bitboard: Bitboard) -> Squares {
+    Squares(bitboard)
+}
+
+pub struct Squares(Bitboard);
+
+impl Iterator for Squares {
+    type Item = Square;
+
+    fn next(&mut self) -> Option<Square> {
+        if self.0 == 0 {
+            return None;
+        }
+
+        let square = lsb(self.0);
+        self.0 &= self.0 - 1;
+
+        Some(square)
+    }
+}
+
+struct Magic {
+    mask: Bitboard,
+    magic: u64,
+    shift: u32,
+    offset: usize,
+}
+
+impl Magic {
+    fn index(&self, occupied: Bitboard) -> usize {
+        self.offset + ((occupied & self.mask).wrapping_mul(self.magic) >> self.shift) as usize
+    }
+}
+
+pub struct AttackTables {
+    knight: [Bitboard; 64],
+    king: [Bitboard; 64],
+    pawn: [[Bitboard; 64]; 2],
+    between: Vec<[Bitboard; 64]>,
+    line: Vec<[Bitboard; 64]>,
+    rook_magics: Vec<Magic>,
+    bishop_magics: Vec<Magic>,
+    sliding: Vec<Bitboard>,
+}
+
+const ROOK_DIRECTIONS: [(i32, i32); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+const BISHOP_DIRECTIONS: [(i32, i32); 4] = [(1, 1), (-1, 1), (1, -1), (-1, -1)];
+
+const KNIGHT_OFFSETS: [(i32, i32); 8] = [
+    (1, 2),
+    (2, 1),
+    (2, -1),
+    (1, -2),
+    (-1, -2),
+    (-2, -1),
+    (-2, 1),
+    (-1, 2),
+];
+
+const KING_OFFSETS: [(i32, i32); 8] = [
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+];
+
+fn step_attacks(square: Square, offsets: &[(i32, i32)]) -> Bitboard {
+    offsets
+        .iter()
+        .filter_map(|&(files, ranks)| square.offset(files, ranks))
+        .fold(0, |attacks, to| attacks | bit(to))
+}
+
+// Walks Each Ray Until It Leaves the Board or Hits an Occupied Square
+fn ray_attacks(square: Square, occupied: Bitboard, directions: &[(i32, i32)]) -> Bitboard {
+    let mut attacks = 0;
+
+    for &(files, ranks) in directions {
+        let mut to = square.offset(files, ranks);
+
+        while let Some(square) = to {
+            attacks |= bit(square);
+
+            if occupied & bit(square) != 0 {
+                break;
+            }
+
+            to = square.offset(files, ranks);
+        }
+    }
+
+    attacks
+}
+
+// Occupancy Bits That Can Block a Slider, Ignoring the Board Edge Each Ray Ends On
+fn relevant_mask(square: Square, directions: &[(i32, i32)]) -> Bitboard {
+    let mut mask = 0;
+
+    for &(files, ranks) in directions {
+        let mut to = square.offset(files, ranks);
+
+        while let Some(square) = to {
+            to = square.offset(files, ranks);
+
+            if to.is_some() {
+                mask |= bit(square);
+            }
+        }
+    }
+
+    mask
+}
+
+fn find_magics(
+    directions: &[(i32, i32)],
+    sliding: &mut Vec<Bitboard>,
+    random: &mut impl FnMut() -> u64,
+) -> Vec<Magic> {
+    let mut magics = Vec::with_capacity(64);
+
+    for square in Square::all() {
+        let mask = relevant_mask(square, directions);
+        let bits = mask.count_ones();
+
+        // Enumerate Every Subset of the Mask (Carry-Rippler)
+        let mut occupancies = Vec::with_capacity(1 << bits);
+        let mut subset: Bitboard = 0;
+
+        loop {
+            occupancies.push((subset, ray_attacks(square, subset, directions)));
+            subset = subset.wrapping_sub(mask) & mask;
+
+            if subset == 0 {
+                break;
+            }
+        }
+
+        let mut table = vec![0; 1 << bits];
+
+        // Slots Written This Attempt Are Stamped With It, Saving a Clear per Candidate
+        let mut used = vec![0u32; 1 << bits];
+        let mut attempt = 0;
+
+        let magic = loop {
+            let candidate = random() & random() & random();
+
+            if (mask.wrapping_mul(candidate) & 0xFF00_0000_0000_0000).count_ones() < 6 {
+                continue;
+            }
+
+            attempt += 1;
+
+            let fits = occupancies.iter().all(|&(occupied, attacks)| {
+                let index = (occupied.wrapping_mul(candidate) >> (64 - bits)) as usize;
+
+                if used[index] == attempt && table[index] != attacks {
+                    return false;
+                }
+
+                used[index] = attempt;
+                table[index] = attacks;
+                true
+            });
+
+            if fits {
+                break candidate;
+            }
+        };
+
+        magics.push(Magic {
+            mask,
+            magic,
+            shift: 64 - bits,
+            offset: sliding.len(),
+        });
+
+        sliding.extend(table);
+    }
+
+    magics
+}
+
+pub static TABLES: Lazy<AttackTables> = Lazy::new(|| {
+    let mut knight = [0; 64];
+    let mut king = [0; 64];
+    let mut pawn = [[0; 64]; 2];
+
+    for square in Square::all() {
+        let index = square.index();
+
+        knight[index] = step_attacks(square, &KNIGHT_OFFSETS);
+        king[index] = step_attacks(square, &KING_OFFSETS);
+        pawn[0][index] = step_attacks(square, &[(1, 1), (-1, 1)]);
+        pawn[1][index] = step_attacks(square, &[(1, -1), (-1, -1)]);
+    }
+
+    let mut between = vec![[0; 64]; 64];
+    let mut line = vec![[0; 64]; 64];
+
+    for from in Square::all() {
+        for directions in [ROOK_DIRECTIONS, BISHOP_DIRECTIONS] {
+            for direction in directions {
+                let ray = ray_attacks(from, 0, &[direction]);
+                let opposite = ray_attacks(from, 0, &[(-direction.0, -direction.1)]);
+
+                for to in squares(ray) {
+                    between[from.index()][to.index()] =
+                        ray & ray_attacks(to, 0, &[(-direction.0, -direction.1)]);
+                    line[from.index()][to.index()] = ray | opposite | bit(from);
+                }
+            }
+        }
+    }
+
+    // Deterministic Xorshift so Start-Up Always Finds the Same Magics
+    let mut seed: u64 = 0x2545_F491_4F6C_DD1D;
+
+    let mut random = move || {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        seed
+    };
+
+    let mut sliding = Vec::new();
+    let rook_magics = find_magics(&ROOK_DIRECTIONS, &mut sliding, &mut random);
+    let bishop_magics = find_magics(&BISHOP_DIRECTIONS, &mut sliding, &mut random);
+
+    AttackTables {
+        knight,
+        king,
+        pawn,
+        between,
+        line,
+        rook_magics,
+        bishop_magics,
+        sliding,
+    }
+});
+
+pub fn knight_attacks(square: Square) -> Bitboard {
+    TABLES.knight[square.index()]
+}
+
+pub fn king_attacks(square: Square) -> Bitboard {
+    TABLES.king[square.index()]
+}
+
+// Squares a Pawn of the Given Side Attacks From `square`
+pub fn pawn_attacks(square: Square, first_player: bool) -> Bitboard {
+    TABLES.pawn[if first_player { 0 } else { 1 }][square.index()]
+}
+
+pub fn rook_attacks(square: Square, occupied: Bitboard) -> Bitboard {
+    let tables = &*TABLES;
+    tables.sliding[tables.rook_magics[square.index()].index(occupied)]
+}
+
+pub fn bishop_attacks(square: Square, occupied: Bitboard) -> Bitboard {
+    let tables = &*TABLES;
+    tables.sliding[tables.bishop_magics[square.index()].index(occupied)]
+}
+
+pub fn queen_attacks(square: Square, occupied: Bitboard) -> Bitboard {
+    rook_attacks(square, occupied) | bishop_attacks(square, occupied)
+}
+
+// Squares Strictly Between Two Squares on a Shared Line, Empty Otherwise
+pub fn between(from: Square, to: Square) -> Bitboard {
+    TABLES.between[from.index()][to.index()]
+}
+
+// The Whole Rank, File or Diagonal Through Both Squares, Empty Otherwise
+pub fn line(from: Square, to: Square) -> Bitboard {
+    TABLES.line[from.index()][to.index()]
+}
@@ -1,7 +1,69 @@
-use super::piece::Piece;
+use super::bitboard::{bit, Bitboard};
+use super::piece::{Piece, PieceType};
+use super::square::Square;
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct Space {
     pub occupied: Option<Piece>,
     pub evaluated_value: i32,
 }
+
+#[derive(Clone)]
+pub struct Board {
+    pub pieces: [[Bitboard; 6]; 2],
+    pub colours: [Bitboard; 2],
+    pub occupied: Bitboard,
+    pub squares: [Option<(PieceType, bool)>; 64],
+}
+
+pub fn side(first_player: bool) -> usize {
+    if first_player {
+        0
+    } else {
+        1
+    }
+}
+
+impl Board {
+    pub fn empty() -> Board {
+        Board {
+            pieces: [[0; 6]; 2],
+            colours: [0; 2],
+            occupied: 0,
+            squares: [None; 64],
+        }
+    }
+
+    pub fn put(&mut self, square: Square, piece_type: PieceType, first_player: bool) {
+        let b = bit(square);
+
+        self.pieces[side(first_player)][piece_type.index()] |= b;
+        self.colours[side(first_player)] |= b;
+        self.occupied |= b;
+        self.squares[square.index()] = Some((piece_type, first_player));
+    }
+
+    pub fn remove(&mut self, square: Square) -> Option<(PieceType, bool)> {
+        let (piece_type, first_player) = self.squares[square.index()]?;
+        let b = bit(square);
+
+        self.pieces[side(first_player)][piece_type.index()] &= !b;
+        self.colours[side(first_player)] &= !b;
+        self.occupied &= !b;
+        self.squares[square.index()] = None;
+
+        Some((piece_type, first_player))
+    }
+
+    pub fn piece_at(&self, square: Square) -> Option<(PieceType, bool)> {
+        self.squares[square.index()]
+    }
+
+    pub fn pieces_of(&self, piece_type: PieceType, first_player: bool) -> Bitboard {
+        self.pieces[side(first_player)][piece_type.index()]
+    }
+
+    pub fn colour(&self, first_player: bool) -> Bitboard {
+        self.colours[side(first_player)]
+    }
+}
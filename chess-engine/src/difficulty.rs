@@ -0,0 +1,226 @@
+use super::moves::Move;
+use super::search::{SearchLimits, MATE_BOUND};
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Difficulty {
+    Beginner,
+    Casual,
+    Intermediate,
+    Advanced,
+    #[default]
+    Expert,
+    // Strength Tuned Towards a Rating Between the Weakest and Strongest Levels
+    Elo(u32),
+}
+
+// How a Computer Player Searches and How Far It Strays From the Best Move
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Strength {
+    pub depth: u32,
+    pub nodes: Option<u64>,
+    // Largest Random Adjustment, in Centipawns, Made to Each Root Move's Score
+    pub noise: i32,
+    // Percentage of Moves Deliberately Chosen From the Runners-Up
+    pub blunder: u32,
+    // How Far Behind the Best Move, in Centipawns, a Runner-Up May Be
+    pub margin: i32,
+}
+
+impl Difficulty {
+    pub const LEVELS: [Difficulty; 5] = [
+        Difficulty::Beginner,
+        Difficulty::Casual,
+        Difficulty::Intermediate,
+        Difficulty::Advanced,
+        Difficulty::Expert,
+    ];
+
+    pub fn from_name(name: &str) -> Option<Difficulty> {
+        match name.to_lowercase().as_str() {
+            "beginner" => Some(Difficulty::Beginner),
+            "casual" => Some(Difficulty::Casual),
+            "intermediate" => Some(Difficulty::Intermediate),
+            "advanced" => Some(Difficulty::Advanced),
+            "expert" => Some(Difficulty::Expert),
+            _ => None,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Difficulty::Beginner => "beginner",
+            Difficulty::Casual => "casual",
+            Difficulty::Intermediate => "intermediate",
+            Difficulty::Advanced => "advanced",
+            Difficulty::Expert => "expert",
+            Difficulty::Elo(_) => "elo",
+        }
+    }
+
+    // Rough Playing Strength, Clamped to the Range the Levels Cover
+    pub fn elo(self) -> u32 {
+        match self {
+            Difficulty::Beginner => 600,
+            Difficulty::Casual => 1000,
+            Difficulty::Intermediate => 1400,
+            Difficulty::Advanced => 1800,
+            Difficulty::Expert => 2200,
+            Difficulty::Elo(elo) => elo.clamp(600, 2200),
+        }
+    }
+
+    pub fn strength(self) -> Strength {
+        match self {
+            Difficulty::Beginner => Strength {
+                depth: 1,
+                nodes: Some(500),
+                noise: 150,
+                blunder: 40,
+                margin: 300,
+            },
+            Difficulty::Casual => Strength {
+                depth: 2,
+                nodes: Some(5_000),
+                noise: 80,
+                blunder: 20,
+                margin: 150,
+            },
+            Difficulty::Intermediate => Strength {
+                depth: 3,
+                nodes: Some(50_000),
+                noise: 30,
+                blunder: 8,
+                margin: 80,
+            },
+            Difficulty::Advanced => Strength {
+                depth: 5,
+                nodes: Some(500_000),
+                noise: 10,
+                blunder: 2,
+                margin: 40,
+            },
+            Difficulty::Expert => Strength {
+                depth: SearchLimits::default().depth,
+                nodes: None,
+                noise: 0,
+                blunder: 0,
+                margin: 0,
+            },
+            Difficulty::Elo(_) => self.interpolated_strength(),
+        }
+    }
+
+    // Searches Like the Level Just Below the Rating, With Mistakes Blended Towards the Level Above
+    fn interpolated_strength(self) -> Strength {
+        let elo = self.elo() as i32;
+
+        let lower = Difficulty::LEVELS
+            .iter()
+            .rev()
+            .find(|x| x.elo() as i32 <= elo)
+            .copied()
+            .unwrap_or(Difficulty::Beginner);
+
+        let upper = Difficulty::LEVELS
+            .iter()
+            .find(|x| x.elo() as i32 >= elo)
+            .copied()
+            .unwrap_or(Difficulty::Expert);
+
+        let (low, high) = (lower.strength(), upper.strength());
+
+        let span = upper.elo() as i32 - lower.elo() as i32;
+        let blend = |from: i32, to: i32| {
+            if span == 0 {
+                from
+            } else {
+                from + (to - from) * (elo - lower.elo() as i32) / span
+            }
+        };
+
+        Strength {
+            noise: blend(low.noise, high.noise),
+            blunder: blend(low.blunder as i32, high.blunder as i32) as u32,
+            margin: blend(low.margin, high.margin),
+            ..low
+        }
+    }
+}
+
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Difficulty::Elo(_) => write!(f, "elo {}", self.elo()),
+            _ => write!(f, "{}", self.name()),
+        }
+    }
+}
+
+impl Strength {
+    pub fn limits(&self) -> SearchLimits {
+        SearchLimits {
+            depth: self.depth,
+            nodes: self.nodes,
+            ..SearchLimits::default()
+        }
+    }
+
+    // Whether Every Root Move Needs Its Own Score Rather Than Just the Best One
+    pub fn is_full_strength(&self) -> bool {
+        self.noise == 0 && self.blunder == 0
+    }
+
+    // Picks From Root Moves Ranked Best First, Never Walking Into a Forced Mate Another Move Avoids
+    pub fn pick(&self, ranked: &[(Move, i32)]) -> Option<Move> {
+        let (_, best_score) = *ranked.first()?;
+
+        let mut rng = Rng::seeded();
+
+        if rng.below(100) < self.blunder {
+            let runners_up: Vec<Move> = ranked[1..]
+                .iter()
+                .filter(|&&(_, score)| score >= best_score - self.margin && score > -MATE_BOUND)
+                .map(|&(mv, _)| mv)
+                .collect();
+
+            if !runners_up.is_empty() {
+                return Some(runners_up[rng.below(runners_up.len() as u32) as usize]);
+            }
+        }
+
+        ranked
+            .iter()
+            .map(|&(mv, score)| {
+                let jitter = rng.below(2 * self.noise as u32 + 1) as i32 - self.noise;
+                (mv, score + jitter)
+            })
+            .max_by_key(|&(_, score)| score)
+            .map(|(mv, _)| mv)
+    }
+}
+
+// Xorshift Seeded From the Clock; Play Only Needs Variety, Not Quality
+struct Rng(u64);
+
+impl Rng {
+    fn seeded() -> Rng {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |x| x.as_nanos() as u64);
+
+        Rng(nanos | 1)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, bound: u32) -> u32 {
+        (self.next() % bound as u64) as u32
+    }
+}
@@ -0,0 +1,476 @@
+use super::bitboard::{
+    bishop_attacks, bit, king_attacks, knight_attacks, pawn_attacks, queen_attacks, rook_attacks,
+    squares, Bitboard, FILE_A,
+};
+use super::board::Space;
+use super::piece::PieceType;
+use super::square::Square;
+use super::state::State;
+use std::fmt;
+
+// Piece-Square Tables From White's Point of View, Laid Out With Rank 8 at the Top
+#[rustfmt::skip]
+const PAWN_TABLE: [i32; 64] = [
+     0,  0,  0,  0,  0,  0,  0,  0,
+    50, 50, 50, 50, 50, 50, 50, 50,
+    10, 10, 20, 30, 30, 20, 10, 10,
+     5,  5, 10, 25, 25, 10,  5,  5,
+     0,  0,  0, 20, 20,  0,  0,  0,
+     5, -5,-10,  0,  0,-10, -5,  5,
+     5, 10, 10,-20,-20, 10, 10,  5,
+     0,  0,  0,  0,  0,  0,  0,  0,
+];
+
+#[rustfmt::skip]
+const KNIGHT_TABLE: [i32; 64] = [
+    -50,-40,-30,-30,-30,-30,-40,-50,
+    -40,-20,  0,  0,  0,  0,-20,-40,
+    -30,  0, 10, 15, 15, 10,  0,-30,
+    -30,  5, 15, 20, 20, 15,  5,-30,
+    -30,  0, 15, 20, 20, 15,  0,-30,
+    -30,  5, 10, 15, 15, 10,  5,-30,
+    -40,-20,  0,  5,  5,  0,-20,-40,
+    -50,-40,-30,-30,-30,-30,-40,-50,
+];
+
+#[rustfmt::skip]
+const BISHOP_TABLE: [i32; 64] = [
+    -20,-10,-10,-10,-10,-10,-10,-20,
+    -10,  0,  0,  0,  0,  0,  0,-10,
+    -10,  0,  5, 10, 10,  5,  0,-10,
+    -10,  5,  5, 10, 10,  5,  5,-10,
+    -10,  0, 10, 10, 10, 10,  0,-10,
+    -10, 10, 10, 10, 10, 10, 10,-10,
+    -10,  5,  0,  0,  0,  0,  5,-10,
+    -20,-10,-10,-10,-10,-10,-10,-20,
+];
+
+#[rustfmt::skip]
+const ROOK_TABLE: [i32; 64] = [
+     0,  0,  0,  0,  0,  0,  0,  0,
+     5, 10, 10, 10, 10, 10, 10,  5,
+    -5,  0,  0,  0,  0,  0,  0, -5,
+    -5,  0,  0,  0,  0,  0,  0, -5,
+    -5,  0,  0,  0,  0,  0,  0, -5,
+    -5,  0,  0,  0,  0,  0,  0, -5,
+    -5,  0,  0,  0,  0,  0,  0, -5,
+     0,  0,  0,  5,  5,  0,  0,  0,
+];
+
+#[rustfmt::skip]
+const QUEEN_TABLE: [i32; 64] = [
+    -20,-10,-10, -5, -5,-10,-10,-20,
+    -10,  0,  0,  0,  0,  0,  0,-10,
+    -10,  0,  5,  5,  5,  5,  0,-10,
+     -5,  0,  5,  5,  5,  5,  0, -5,
+      0,  0,  5,  5,  5,  5,  0, -5,
+    -10,  5,  5,  5,  5,  5,  0,-10,
+    -10,  0,  5,  0,  0,  0,  0,-10,
+    -20,-10,-10, -5, -5,-10,-10,-20,
+];
+
+#[rustfmt::skip]
+const KING_MIDDLEGAME_TABLE: [i32; 64] = [
+    -30,-40,-40,-50,-50,-40,-40,-30,
+    -30,-40,-40,-50,-50,-40,-40,-30,
+    -30,-40,-40,-50,-50,-40,-40,-30,
+    -30,-40,-40,-50,-50,-40,-40,-30,
+    -20,-30,-30,-40,-40,-30,-30,-20,
+    -10,-20,-20,-20,-20,-20,-20,-10,
+     20, 20,  0,  0,  0,  0, 20, 20,
+     20, 30, 10,  0,  0, 10, 30, 20,
+];
+
+#[rustfmt::skip]
+const KING_ENDGAME_TABLE: [i32; 64] = [
+    -50,-40,-30,-20,-20,-30,-40,-50,
+    -30,-20,-10,  0,  0,-10,-20,-30,
+    -30,-10, 20, 30, 30, 20,-10,-30,
+    -30,-10, 30, 40, 40, 30,-10,-30,
+    -30,-10, 30, 40, 40, 30,-10,-30,
+    -30,-10, 20, 30, 30, 20,-10,-30,
+    -30,-30,  0,  0,  0,  0,-30,-30,
+    -50,-30,-30,-30,-30,-30,-30,-50,
+];
+
+// Bonus for a Passed Pawn by How Far It Has Advanced, Counted From Its Own Back Rank
+const PASSED_PAWN: [i32; 8] = [0, 5, 10, 20, 35, 60, 100, 0];
+
+const DOUBLED_PAWN: i32 = -10;
+const ISOLATED_PAWN: i32 = -15;
+
+const PAWN_SHIELD: i32 = 10;
+const OPEN_FILE_NEAR_KING: i32 = -15;
+
+// Non-Pawn Material at the Start of the Game, With Minors 1, Rooks 2 and Queens 4
+const FULL_PHASE: i32 = 24;
+
+pub fn piece_value(piece_type: PieceType) -> i32 {
+    match piece_type {
+        PieceType::Pawn => 100,
+        PieceType::Knight => 320,
+        PieceType::Bishop => 330,
+        PieceType::Rook => 500,
+        PieceType::Queen => 900,
+        PieceType::King => 0,
+    }
+}
+
+fn phase_weight(piece_type: PieceType) -> i32 {
+    match piece_type {
+        PieceType::Knight | PieceType::Bishop => 1,
+        PieceType::Rook => 2,
+        PieceType::Queen => 4,
+        _ => 0,
+    }
+}
+
+// Centipawns per Safe Square a Piece Can Reach
+fn mobility_weight(piece_type: PieceType) -> i32 {
+    match piece_type {
+        PieceType::Knight | PieceType::Bishop => 4,
+        PieceType::Rook => 2,
+        PieceType::Queen => 1,
+        _ => 0,
+    }
+}
+
+// How Much a Piece Bearing Down on the King's Surroundings Counts Against Its Safety
+fn king_attack_weight(piece_type: PieceType) -> i32 {
+    match piece_type {
+        PieceType::Knight | PieceType::Bishop => 2,
+        PieceType::Rook => 3,
+        PieceType::Queen => 5,
+        _ => 0,
+    }
+}
+
+fn file_mask(file: i32) -> Bitboard {
+    FILE_A << (file - 1)
+}
+
+fn adjacent_files(file: i32) -> Bitboard {
+    let mut mask = 0;
+
+    if file > 1 {
+        mask |= file_mask(file - 1);
+    }
+
+    if file < 8 {
+        mask |= file_mask(file + 1);
+    }
+
+    mask
+}
+
+// Every Rank Strictly in Front of the Given One From the Given Side's Point of View
+fn ranks_ahead(rank: i32, first_player: bool) -> Bitboard {
+    if first_player {
+        if rank == 8 {
+            0
+        } else {
+            !0 << (8 * rank)
+        }
+    } else {
+        (1 << (8 * (rank - 1))) - 1
+    }
+}
+
+fn table_value(piece_type: PieceType, square: Square, first_player: bool, phase: i32) -> i32 {
+    // Tables Are Written for White, so White's Squares Are Flipped Vertically Into Them
+    let index = if first_player {
+        square.index() ^ 56
+    } else {
+        square.index()
+    };
+
+    match piece_type {
+        PieceType::Pawn => PAWN_TABLE[index],
+        PieceType::Knight => KNIGHT_TABLE[index],
+        PieceType::Bishop => BISHOP_TABLE[index],
+        PieceType::Rook => ROOK_TABLE[index],
+        PieceType::Queen => QUEEN_TABLE[index],
+        PieceType::King => {
+            (KING_MIDDLEGAME_TABLE[index] * phase
+                + KING_ENDGAME_TABLE[index] * (FULL_PHASE - phase))
+                / FULL_PHASE
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Term {
+    Material,
+    Placement,
+    Mobility,
+    KingSafety,
+    PawnStructure,
+}
+
+// Collects Each Term Both as a Total and Against the Square Responsible for It
+struct Evaluator {
+    terms: [i32; 5],
+    values: [i32; 64],
+}
+
+impl Evaluator {
+    fn add(&mut self, term: Term, square: Square, first_player: bool, score: i32) {
+        let score = if first_player { score } else { -score };
+
+        self.terms[term as usize] += score;
+        self.values[square.index()] += score;
+    }
+}
+
+// A Position's Score in Centipawns From White's Point of View, Split Into Its Terms
+#[derive(Clone, Debug)]
+pub struct Evaluation {
+    pub material: i32,
+    pub placement: i32,
+    pub mobility: i32,
+    pub king_safety: i32,
+    pub pawn_structure: i32,
+    pub spaces: Vec<Space>,
+}
+
+impl Evaluation {
+    pub fn total(&self) -> i32 {
+        self.material + self.placement + self.mobility + self.king_safety + self.pawn_structure
+    }
+
+    pub fn space(&self, square: Square) -> &Space {
+        &self.spaces[square.index()]
+    }
+}
+
+impl fmt::Display for Evaluation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "material        {:>6}", self.material)?;
+        writeln!(f, "placement       {:>6}", self.placement)?;
+        writeln!(f, "mobility        {:>6}", self.mobility)?;
+        writeln!(f, "king safety     {:>6}", self.king_safety)?;
+        writeln!(f, "pawn structure  {:>6}", self.pawn_structure)?;
+        writeln!(f, "total           {:>6}", self.total())?;
+
+        // Per-Square Contributions, Rank 8 First
+        for rank in (1..=8).rev() {
+            writeln!(f)?;
+            write!(f, "{} ", rank)?;
+
+            for file in 1..=8 {
+                let space = self.space(Square::new(file, rank).unwrap());
+                write!(f, "{:>6}", space.evaluated_value)?;
+            }
+        }
+
+        writeln!(f)?;
+        write!(f, " ")?;
+
+        for file in 'a'..='h' {
+            write!(f, "{:>6}", file)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl State {
+    // Full Evaluation With Every Square's Share of the Score
+    pub fn evaluate(&self) -> Evaluation {
+        let evaluator = self.run_evaluator();
+
+        let spaces = Square::all()
+            .map(|square| Space {
+                occupied: self.piece_at(square),
+                evaluated_value: evaluator.values[square.index()],
+            })
+            .collect();
+
+        Evaluation {
+            material: evaluator.terms[Term::Material as usize],
+            placement: evaluator.terms[Term::Placement as usize],
+            mobility: evaluator.terms[Term::Mobility as usize],
+            king_safety: evaluator.terms[Term::KingSafety as usize],
+            pawn_structure: evaluator.terms[Term::PawnStructure as usize],
+            spaces,
+        }
+    }
+
+    // The Same Score From the Side to Move's Point of View, as the Search Wants It
+    pub fn relative_score(&self) -> i32 {
+        let score: i32 = self.run_evaluator().terms.iter().sum();
+
+        if self.first_player_turn {
+            score
+        } else {
+            -score
+        }
+    }
+
+    // Zero Once Only Kings and Pawns Remain, Full at the Starting Material
+    pub fn game_phase(&self) -> i32 {
+        let phase: i32 = [true, false]
+            .iter()
+            .flat_map(|&first_player| {
+                PieceType::ALL.iter().map(move |&piece_type| {
+                    self.board.pieces_of(piece_type, first_player).count_ones() as i32
+                        * phase_weight(piece_type)
+                })
+            })
+            .sum();
+
+        phase.min(FULL_PHASE)
+    }
+
+    fn run_evaluator(&self) -> Evaluator {
+        let mut evaluator = Evaluator {
+            terms: [0; 5],
+            values: [0; 64],
+        };
+
+        let phase = self.game_phase();
+
+        for first_player in [true, false] {
+            self.evaluate_pieces(&mut evaluator, first_player, phase);
+            self.evaluate_pawns(&mut evaluator, first_player);
+            self.evaluate_king(&mut evaluator, first_player, phase);
+        }
+
+        evaluator
+    }
+
+    fn evaluate_pieces(&self, evaluator: &mut Evaluator, first_player: bool, phase: i32) {
+        let board = &self.board;
+        let occupied = board.occupied;
+
+        // Squares Covered by Enemy Pawns Do Not Count Towards Mobility
+        let enemy_pawn_attacks = squares(board.pieces_of(PieceType::Pawn, !first_player))
+            .fold(0, |acc, square| acc | pawn_attacks(square, !first_player));
+
+        let safe = !board.colour(first_player) & !enemy_pawn_attacks;
+
+        for piece_type in PieceType::ALL {
+            for square in squares(board.pieces_of(piece_type, first_player)) {
+                evaluator.add(
+                    Term::Material,
+                    square,
+                    first_player,
+                    piece_value(piece_type),
+                );
+
+                evaluator.add(
+                    Term::Placement,
+                    square,
+                    first_player,
+                    table_value(piece_type, square, first_player, phase),
+                );
+
+                let attacks = match piece_type {
+                    PieceType::Knight => knight_attacks(square),
+                    PieceType::Bishop => bishop_attacks(square, occupied),
+                    PieceType::Rook => rook_attacks(square, occupied),
+                    PieceType::Queen => queen_attacks(square, occupied),
+                    _ => continue,
+                };
+
+                evaluator.add(
+                    Term::Mobility,
+                    square,
+                    first_player,
+                    (attacks & safe).count_ones() as i32 * mobility_weight(piece_type),
+                );
+            }
+        }
+    }
+
+    fn evaluate_pawns(&self, evaluator: &mut Evaluator, first_player: bool) {
+        let own = self.board.pieces_of(PieceType::Pawn, first_player);
+        let enemy = self.board.pieces_of(PieceType::Pawn, !first_player);
+
+        for square in squares(own) {
+            let file = square.file();
+            let ahead = ranks_ahead(square.rank(), first_player);
+
+            let mut score = 0;
+
+            // Only the Pawns Stuck Behind Another Are Counted as Doubled
+            if own & file_mask(file) & ahead != 0 {
+                score += DOUBLED_PAWN;
+            }
+
+            if own & adjacent_files(file) == 0 {
+                score += ISOLATED_PAWN;
+            }
+
+            if enemy & (file_mask(file) | adjacent_files(file)) & ahead == 0 {
+                let advanced = if first_player {
+                    square.rank() - 1
+                } else {
+                    8 - square.rank()
+                };
+
+                score += PASSED_PAWN[advanced as usize];
+            }
+
+            evaluator.add(Term::PawnStructure, square, first_player, score);
+        }
+    }
+
+    // Pawn Cover and Enemy Pressure Around the King, Fading Out as Material Comes Off
+    fn evaluate_king(&self, evaluator: &mut Evaluator, first_player: bool, phase: i32) {
+        let board = &self.board;
+        let king_square = self.king_square(first_player);
+        let pawns = board.pieces_of(PieceType::Pawn, first_player);
+
+        let mut score = 0;
+
+        let home_rank = if first_player { 1 } else { 8 };
+        let forward = if first_player { 1 } else { -1 };
+
+        if (king_square.rank() - home_rank).abs() <= 1 {
+            for files in -1..=1 {
+                for ranks in 1..=2 {
+                    if let Some(square) = king_square.offset(files, ranks * forward) {
+                        if pawns & bit(square) != 0 {
+                            score += PAWN_SHIELD / ranks;
+                        }
+                    }
+                }
+
+                let file = king_square.file() + files;
+
+                if (1..=8).contains(&file) && pawns & file_mask(file) == 0 {
+                    score += OPEN_FILE_NEAR_KING;
+                }
+            }
+        }
+
+        let zone = king_attacks(king_square) | bit(king_square);
+        let occupied = board.occupied;
+
+        for piece_type in [
+            PieceType::Queen,
+            PieceType::Rook,
+            PieceType::Bishop,
+            PieceType::Knight,
+        ] {
+            for square in squares(board.pieces_of(piece_type, !first_player)) {
+                let attacks = match piece_type {
+                    PieceType::Queen => queen_attacks(square, occupied),
+                    PieceType::Rook => rook_attacks(square, occupied),
+                    PieceType::Bishop => bishop_attacks(square, occupied),
+                    _ => knight_attacks(square),
+                };
+
+                score -= (attacks & zone).count_ones() as i32 * king_attack_weight(piece_type);
+            }
+        }
+
+        evaluator.add(
+            Term::KingSafety,
+            king_square,
+            first_player,
+            score * phase / FULL_PHASE,
+        );
+    }
+}
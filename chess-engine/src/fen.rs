@@ -0,0 +1,303 @@
+use super::bitboard::squares;
+use super::piece::PieceType;
+use super::square::Square;
+use super::state::{CastlingRights, State};
+use std::fmt;
+
+pub const START_FEN: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+
+#[derive(Debug, PartialEq)]
+pub enum FenError {
+    FieldCount(usize),
+    RankCount(usize),
+    RankLength(i32, usize),
+    InvalidPiece(char),
+    KingCount(bool, usize),
+    SideToMove(String),
+    Castling(String),
+    EnPassant(String),
+    PawnOnBackRank(Square),
+    NoEnPassantPawn(Square),
+    OpponentInCheck,
+    HalfmoveClock(String),
+    FullmoveNumber(String),
+}
+
+impl fmt::Display for FenError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FenError::FieldCount(count) => {
+                write!(f, "expected 4 or 6 space-separated fields, found {}", count)
+            }
+            FenError::RankCount(count) => {
+                write!(f, "expected 8 ranks in piece placement, found {}", count)
+            }
+            FenError::RankLength(rank, length) => {
+                write!(f, "rank {} describes {} squares instead of 8", rank, length)
+            }
+            FenError::InvalidPiece(c) => write!(f, "invalid piece character '{}'", c),
+            FenError::KingCount(first_player, count) => write!(
+                f,
+                "{} must have exactly one king, found {}",
+                if *first_player { "white" } else { "black" },
+                count
+            ),
+            FenError::SideToMove(field) => {
+                write!(f, "side to move must be 'w' or 'b', found '{}'", field)
+            }
+            FenError::Castling(field) => write!(f, "invalid castling rights '{}'", field),
+            FenError::EnPassant(field) => write!(f, "invalid en passant square '{}'", field),
+            FenError::PawnOnBackRank(square) => write!(f, "pawn on the back rank at {}", square),
+            FenError::NoEnPassantPawn(square) => write!(
+                f,
+                "en passant square {} does not follow a double pawn push",
+                square
+            ),
+            FenError::OpponentInCheck => write!(f, "the side not to move is in check"),
+            FenError::HalfmoveClock(field) => write!(f, "invalid halfmove clock '{}'", field),
+            FenError::FullmoveNumber(field) => write!(f, "invalid fullmove number '{}'", field),
+        }
+    }
+}
+
+impl std::error::Error for FenError {}
+
+fn piece_from_char(c: char) -> Option<(PieceType, bool)> {
+    let piece_type = match c.to_ascii_lowercase() {
+        'k' => PieceType::King,
+        'q' => PieceType::Queen,
+        'r' => PieceType::Rook,
+        'b' => PieceType::Bishop,
+        'n' => PieceType::Knight,
+        'p' => PieceType::Pawn,
+        _ => return None,
+    };
+
+    Some((piece_type, c.is_ascii_uppercase()))
+}
+
+fn piece_to_char(piece_type: PieceType, first_player: bool) -> char {
+    let c = match piece_type {
+        PieceType::King => 'k',
+        PieceType::Queen => 'q',
+        PieceType::Rook => 'r',
+        PieceType::Bishop => 'b',
+        PieceType::Knight => 'n',
+        PieceType::Pawn => 'p',
+    };
+
+    if first_player {
+        c.to_ascii_uppercase()
+    } else {
+        c
+    }
+}
+
+impl State {
+    pub fn from_fen(fen: &str) -> Result<State, FenError> {
+        let fields: Vec<&str> = fen.split_whitespace().collect();
+
+        if fields.len() != 4 && fields.len() != 6 {
+            return Err(FenError::FieldCount(fields.len()));
+        }
+
+        let mut state = State::empty();
+
+        // Piece Placement
+        let ranks: Vec<&str> = fields[0].split('/').collect();
+
+        if ranks.len() != 8 {
+            return Err(FenError::RankCount(ranks.len()));
+        }
+
+        for (i, rank) in ranks.iter().enumerate() {
+            let y = 8 - i as i32;
+            let mut x = 1;
+
+            for c in rank.chars() {
+                if let Some(skip) = c.to_digit(10).filter(|x| (1..=8).contains(x)) {
+                    x += skip as usize;
+                    continue;
+                }
+
+                let (piece_type, first_player) =
+                    piece_from_char(c).ok_or(FenError::InvalidPiece(c))?;
+
+                let square = Square::new(x as i32, y).ok_or(FenError::RankLength(y, x))?;
+
+                state.place_piece(piece_type, square, first_player);
+                x += 1;
+            }
+
+            if x != 9 {
+                return Err(FenError::RankLength(y, x - 1));
+            }
+        }
+
+        for first_player in [true, false] {
+            let kings = state
+                .board
+                .pieces_of(PieceType::King, first_player)
+                .count_ones() as usize;
+
+            if kings != 1 {
+                return Err(FenError::KingCount(first_player, kings));
+            }
+        }
+
+        // Pawns Can Neither Start Nor Stay on the First or Last Rank
+        if let Some(square) = squares(
+            state.board.pieces_of(PieceType::Pawn, true)
+                | state.board.pieces_of(PieceType::Pawn, false),
+        )
+        .find(|x| x.rank() == 1 || x.rank() == 8)
+        {
+            return Err(FenError::PawnOnBackRank(square));
+        }
+
+        // Side to Move
+        state.first_player_turn = match fields[1] {
+            "w" => true,
+            "b" => false,
+            field => return Err(FenError::SideToMove(field.to_string())),
+        };
+
+        // Castling Rights
+        if fields[2] != "-" {
+            let mut castling = state.castling;
+
+            for c in fields[2].chars() {
+                let right = match c {
+                    'K' => &mut castling.white_kingside,
+                    'Q' => &mut castling.white_queenside,
+                    'k' => &mut castling.black_kingside,
+                    'q' => &mut castling.black_queenside,
+                    _ => return Err(FenError::Castling(fields[2].to_string())),
+                };
+
+                if *right {
+                    return Err(FenError::Castling(fields[2].to_string()));
+                }
+
+                *right = true;
+            }
+
+            state.castling = castling;
+        }
+
+        // En Passant
+        if fields[3] != "-" {
+            let rank = if state.first_player_turn { 6 } else { 3 };
+
+            state.en_passant = match fields[3].parse::<Square>() {
+                Ok(square) if square.rank() == rank => Some(square),
+                _ => return Err(FenError::EnPassant(fields[3].to_string())),
+            };
+        }
+
+        // The Pawn That Just Pushed Two Squares Must Be There, With Both Squares It Crossed Empty
+        if let Some(square) = state.en_passant {
+            let behind = if state.first_player_turn { 1 } else { -1 };
+
+            let pushed = square.offset(0, -behind).unwrap();
+            let origin = square.offset(0, behind).unwrap();
+
+            let pawn_present =
+                state.board.piece_at(pushed) == Some((PieceType::Pawn, !state.first_player_turn));
+
+            if !pawn_present || !state.is_empty(&[square, origin]) {
+                return Err(FenError::NoEnPassantPawn(square));
+            }
+        }
+
+        // Clocks
+        if fields.len() == 6 {
+            state.halfmove_clock = fields[4]
+                .parse()
+                .map_err(|_| FenError::HalfmoveClock(fields[4].to_string()))?;
+
+            state.fullmove_number = fields[5]
+                .parse()
+                .ok()
+                .filter(|&x| x >= 1)
+                .ok_or_else(|| FenError::FullmoveNumber(fields[5].to_string()))?;
+        }
+
+        // Otherwise the Side to Move Could Capture the King
+        if state.is_in_check(!state.first_player_turn) {
+            return Err(FenError::OpponentInCheck);
+        }
+
+        state.reset_hash();
+
+        Ok(state)
+    }
+
+    pub fn to_fen(&self) -> String {
+        let mut ranks = Vec::with_capacity(8);
+
+        for y in (1..=8).rev() {
+            let mut rank = String::new();
+            let mut empty = 0;
+
+            for x in 1..=8 {
+                match self.board.piece_at(Square::new(x, y).unwrap()) {
+                    Some((piece_type, first_player)) => {
+                        if empty > 0 {
+                            rank.push_str(&empty.to_string());
+                            empty = 0;
+                        }
+
+                        rank.push(piece_to_char(piece_type, first_player));
+                    }
+                    None => empty += 1,
+                }
+            }
+
+            if empty > 0 {
+                rank.push_str(&empty.to_string());
+            }
+
+            ranks.push(rank);
+        }
+
+        let side = if self.first_player_turn { "w" } else { "b" };
+
+        let CastlingRights {
+            white_kingside,
+            white_queenside,
+            black_kingside,
+            black_queenside,
+        } = self.castling;
+
+        let mut castling: String = [
+            (white_kingside, 'K'),
+            (white_queenside, 'Q'),
+            (black_kingside, 'k'),
+            (black_queenside, 'q'),
+        ]
+        .iter()
+        .filter(|(right, _)| *right)
+        .map(|(_, c)| *c)
+        .collect();
+
+        if castling.is_empty() {
+            castling.push('-');
+        }
+
+        let en_passant = self
+            .en_passant
+            .map(|x| x.to_string())
+            .unwrap_or_else(|| "-".to_string());
+
+        format!(
+            "{} {} {} {} {} {}",
+            ranks.join("/"),
+            side,
+            castling,
+            en_passant,
+            self.halfmove_clock,
+            self.fullmove_number
+        )
+    }
+}
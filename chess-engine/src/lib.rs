@@ -0,0 +1,20 @@
+pub mod bitboard;
+pub mod board;
+pub mod difficulty;
+pub mod eval;
+pub mod fen;
+pub mod material;
+pub mod movegen;
+pub mod moves;
+pub mod outcome;
+pub mod perft;
+pub mod pgn;
+pub mod piece;
+pub mod player;
+pub mod san;
+pub mod search;
+pub mod see;
+pub mod square;
+pub mod state;
+pub mod tt;
+pub mod zobrist;
@@ -1,59 +1,182 @@
+use chess_engine::difficulty::Difficulty;
+use chess_engine::fen::START_FEN;
+use chess_engine::moves::{Move, MoveError};
+use chess_engine::pgn::{date_tag, parse_pgn, Pgn};
+use chess_engine::piece::PieceType;
+use chess_engine::player::Player;
+use chess_engine::square::Square;
+use chess_engine::state::State;
+use chess_engine::tt::DEFAULT_SIZE_MB;
 use futures_util::{SinkExt, StreamExt, TryFutureExt};
-use serde::{Deserialize, Serialize};
-use serde_json;
-use state::State;
+use serde_json::json;
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
 use tokio::sync::mpsc;
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
+use tokio::sync::RwLock;
 use tokio_stream::wrappers::UnboundedReceiverStream;
 use uuid::Uuid;
 use warp::ws::{Message, WebSocket};
 use warp::Filter;
 
-mod board;
-mod piece;
-mod player;
-mod state;
-mod utils;
+// Largest Transposition Table a Client May Ask For; Every Game Holds One for as Long as It Lasts
+const MAX_HASH_MB: usize = 64;
+
+// How Long a Finished Game Stays Downloadable After Its Player Leaves
+const ARCHIVE_RETENTION: Duration = Duration::from_secs(24 * 60 * 60);
+
+// A Game Set Up Over the Websocket, Dated From When Setup Created It
+struct Game {
+    state: State,
+    started: SystemTime,
+}
+
+// PGN of a Finished Game Whose Connection Has Closed
+struct Archived {
+    pgn: String,
+    stored: Instant,
+}
+
+type Games = Arc<RwLock<HashMap<String, Option<Game>>>>;
+type Archive = Arc<RwLock<HashMap<String, Archived>>>;
 
 #[tokio::main]
 async fn main() {
-    let games: HashMap<String, Option<State>> = HashMap::new();
+    // Perft Command: `chess-engine perft <depth> [fen]`
+    let args: Vec<String> = std::env::args().collect();
+
+    if args.get(1).map(String::as_str) == Some("perft") {
+        let depth = args.get(2).and_then(|x| x.parse().ok()).unwrap_or(1);
+        let fen = args.get(3).map(String::as_str).unwrap_or(START_FEN);
+        run_perft(depth, fen);
+        return;
+    }
+
+    // Replay Command: `chess-engine replay <file.pgn>`
+    if args.get(1).map(String::as_str) == Some("replay") {
+        match args.get(2) {
+            Some(path) => run_replay(path),
+            None => println!("usage: chess-engine replay <file.pgn>"),
+        }
+        return;
+    }
+
+    // Evaluation Command: `chess-engine eval [fen]`
+    if args.get(1).map(String::as_str) == Some("eval") {
+        run_eval(args.get(2).map(String::as_str).unwrap_or(START_FEN));
+        return;
+    }
+
+    let games = Games::default();
+    let archive = Archive::default();
 
     let games = warp::any().map(move || games.clone());
+    let archive = warp::any().map(move || archive.clone());
 
     let route = warp::path("game")
+        .and(warp::path::end())
         .and(warp::ws())
-        .and(games)
-        .map(|ws: warp::ws::Ws, games| {
-            ws.on_upgrade(move |socket| handle_connection(socket, games))
+        .and(games.clone())
+        .and(archive.clone())
+        .map(|ws: warp::ws::Ws, games, archive| {
+            ws.on_upgrade(move |socket| handle_connection(socket, games, archive))
         });
 
-    let routes = route.with(warp::cors().allow_any_origin());
+    // PGN Download: `GET /game/<id>/pgn`
+    let pgn = warp::path!("game" / String / "pgn")
+        .and(warp::get())
+        .and(games)
+        .and(archive)
+        .and_then(download_pgn);
+
+    let routes = route.or(pgn).with(warp::cors().allow_any_origin());
 
     warp::serve(routes).run(([127, 0, 0, 1], 3030)).await;
 }
 
-async fn handle_connection(ws: WebSocket, mut games: HashMap<String, Option<State>>) {
+fn run_perft(depth: u32, fen: &str) {
+    let mut state = match State::from_fen(fen) {
+        Ok(state) => state,
+        Err(e) => {
+            println!("invalid fen: {}", e);
+            return;
+        }
+    };
+
+    let mut total = 0;
+
+    for (mv, nodes) in state.divide(depth) {
+        println!("{}: {}", mv, nodes);
+        total += nodes;
+    }
+
+    println!("\nNodes searched: {}", total);
+}
+
+fn run_eval(fen: &str) {
+    match State::from_fen(fen) {
+        Ok(state) => println!("{}", state.evaluate()),
+        Err(e) => println!("invalid fen: {}", e),
+    }
+}
+
+fn run_replay(path: &str) {
+    let text = match std::fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) => {
+            println!("cannot read {}: {}", path, e);
+            return;
+        }
+    };
+
+    let games = match parse_pgn(&text) {
+        Ok(games) => games,
+        Err(e) => {
+            println!("invalid pgn: {}", e);
+            return;
+        }
+    };
+
+    for (i, game) in games.iter().enumerate() {
+        match game.replay() {
+            Ok(state) => println!(
+                "game {}: {} moves, {}",
+                i + 1,
+                game.moves.len(),
+                state.to_fen()
+            ),
+            Err(e) => println!("game {}: {}", i + 1, e),
+        }
+    }
+}
+
+async fn handle_connection(ws: WebSocket, games: Games, archive: Archive) {
     // Generate New ID
     let id = Uuid::new_v4();
 
     // Add Game
-    games.insert(id.to_string(), None);
+    games.write().await.insert(id.to_string(), None);
 
     let (mut sender, mut receiver) = ws.split();
 
-    let (tx, rx): (UnboundedSender<i32>, UnboundedReceiver<i32>) = mpsc::unbounded_channel();
+    let (tx, rx): (UnboundedSender<Message>, UnboundedReceiver<Message>) =
+        mpsc::unbounded_channel();
     let mut rx = UnboundedReceiverStream::new(rx);
 
-    let message = Message::text(format!("id: {}", id.to_string()));
+    // Forward Outgoing Messages
+    tokio::task::spawn(async move {
+        while let Some(message) = rx.next().await {
+            sender
+                .send(message)
+                .unwrap_or_else(|e| {
+                    println!("websocket send error: {}", e);
+                })
+                .await;
+        }
+    });
 
-    sender
-        .send(message)
-        .unwrap_or_else(|e| {
-            println!("websocket send error: {}", e);
-        })
-        .await;
+    send(&tx, format!("id: {}", id));
 
     // Receive Messages
     while let Some(result) = receiver.next().await {
@@ -65,30 +188,429 @@ async fn handle_connection(ws: WebSocket, mut games: HashMap<String, Option<Stat
             }
         };
 
-        let message = msg.to_str().unwrap();
-        let data: serde_json::Value = serde_json::from_str(message).unwrap();
+        let message = match msg.to_str() {
+            Ok(message) => message,
+            Err(_) => continue,
+        };
 
-        let id = &data["id"];
-        let action = &data["action"];
+        let data: serde_json::Value = match serde_json::from_str(message) {
+            Ok(data) => data,
+            Err(e) => {
+                println!("websocket parse error(uid={}): {}", id, e);
+                continue;
+            }
+        };
+
+        let game_id = data["id"].as_str().unwrap_or_default().trim();
+        let action = data["action"].as_str().unwrap_or_default();
 
-        match action.as_str().unwrap() {
+        match action {
             "setup" => {
-                let id_val = id.as_str().unwrap();
+                let ai = data["ai"].as_bool().unwrap_or(false);
+
+                let hash_mb = data["hash_mb"]
+                    .as_u64()
+                    .map_or(DEFAULT_SIZE_MB, |x| x as usize)
+                    .clamp(1, MAX_HASH_MB);
+
+                // A Named Level Takes Priority Over an Elo Target
+                let difficulty = match (data["difficulty"].as_str(), data["elo"].as_u64()) {
+                    (Some(name), _) => Difficulty::from_name(name)
+                        .ok_or_else(|| format!("unknown difficulty '{}'", name)),
+                    (None, Some(elo)) => Ok(Difficulty::Elo(elo as u32)),
+                    _ => Ok(Difficulty::default()),
+                };
 
-                let game = games.get_mut(id_val).unwrap();
+                let difficulty = match difficulty {
+                    Ok(difficulty) => difficulty,
+                    Err(e) => {
+                        send(&tx, setup_rejected(&e).to_string());
+                        continue;
+                    }
+                };
 
-                // game = Some()
+                // Filling the Table Takes a While, so It Happens Before Any Lock Is Taken
+                let state = tokio::task::spawn_blocking(move || {
+                    let mut state = State::with_table_size(ai, hash_mb);
+                    state.black.difficulty = difficulty;
+                    state
+                })
+                .await;
+
+                let reply = match (games.write().await.get_mut(game_id), state) {
+                    (Some(game), Ok(state)) => {
+                        *game = Some(Game {
+                            state,
+                            started: SystemTime::now(),
+                        });
+
+                        json!({
+                            "action": "setup",
+                            "accepted": true,
+                            "ai": ai,
+                            "difficulty": difficulty.name(),
+                            "elo": difficulty.elo(),
+                            "hash_mb": hash_mb,
+                            "levels": Difficulty::LEVELS.map(Difficulty::name),
+                        })
+                    }
+                    (None, _) => setup_rejected("unknown game"),
+                    (_, Err(_)) => setup_rejected("the game could not be created"),
+                };
+
+                send(&tx, reply.to_string());
+            }
+            "move" => {
+                let uci = data["move"].as_str();
+                let current_coords = parse_coords(&data["from"]);
+                let destination = parse_coords(&data["to"]);
+                let promotion = data["promotion"].as_str().and_then(PieceType::from_name);
+
+                let mut outcome = None;
+
+                let reply = match games.write().await.get_mut(game_id) {
+                    Some(Some(Game { state: game, .. })) => {
+                        // UCI Strings Take Priority Over `from` / `to` Coordinates
+                        let resolved = match (uci, current_coords, destination) {
+                            _ if game.outcome.is_some() => Err(MoveError::GameOver),
+                            (Some(uci), _, _) => game.parse_uci(uci),
+                            (None, Some(from), Some(to)) => game.find_move(from, to, promotion),
+                            _ => Err(MoveError::OffBoard),
+                        };
+
+                        let player = if game.first_player_turn {
+                            game.white.clone()
+                        } else {
+                            game.black.clone()
+                        };
+
+                        // Name the Move Before It Is Played, While Disambiguation Still Applies
+                        let played = resolved.and_then(|mv| {
+                            let san = game.move_to_san(mv);
+                            player.play(game, mv).map(|_| (mv, san))
+                        });
+
+                        match played {
+                            Ok((mv, san)) => {
+                                outcome = game.outcome;
+                                move_accepted("move", game, mv, san)
+                            }
+                            Err(e) => move_rejected(&e.to_string()),
+                        }
+                    }
+                    _ => move_rejected("no game in progress"),
+                };
+
+                send(&tx, reply.to_string());
+
+                if let Some(outcome) = outcome {
+                    send(&tx, format!("outcome: {} {}", outcome.result(), outcome));
+                }
+
+                // The Computer Answers as Soon as the Human's Move Lands
+                if reply["accepted"] == true && outcome.is_none() {
+                    play_ai_move(&games, game_id, &tx).await;
+                }
+            }
+            "undo" => {
+                let mut undone = false;
+
+                // Resignations, Agreed or Claimed Draws and Timeouts Cannot Be Taken Back
+                if let Some(Some(Game { state: game, .. })) = games.write().await.get_mut(game_id) {
+                    if !game.outcome.is_some_and(|x| x.is_declared()) {
+                        undone = game.unmake_move().is_some();
+
+                        // Against the Computer Its Reply Goes Too, Handing the Move Back to the Human
+                        if undone && player_to_move(game).ai {
+                            game.unmake_move();
+                        }
+                    }
+                }
+
+                let reply = if undone { "accepted" } else { "rejected" };
+
+                send(&tx, format!("undo: {}", reply));
+            }
+            "resign" | "claim" | "draw" | "accept_draw" | "decline_draw" => {
+                let result = match games.write().await.get_mut(game_id) {
+                    Some(Some(Game { state: game, .. })) => {
+                        let side = acting_side(game, data["side"].as_str());
+
+                        match action {
+                            "resign" => side.and_then(|x| game.resign(x)).map(Some),
+                            "claim" => game.claim_draw().map(Some),
+                            "draw" => side.and_then(|x| game.offer_draw(x)).map(|_| None),
+                            "accept_draw" => side.and_then(|x| game.accept_draw(x)).map(Some),
+                            _ => side.and_then(|x| game.decline_draw(x)).map(|_| None),
+                        }
+                    }
+                    _ => continue,
+                };
+
+                match result {
+                    Ok(Some(outcome)) => {
+                        send(&tx, format!("outcome: {} {}", outcome.result(), outcome))
+                    }
+                    Ok(None) if action == "draw" => send(&tx, "draw: offered".to_string()),
+                    Ok(None) => send(&tx, "draw: declined".to_string()),
+                    Err(e) => send(&tx, format!("{}: rejected ({})", action, e)),
+                }
+
+                // The Computer Answers an Offer Straight Away
+                if action == "draw" && result.is_ok() {
+                    answer_draw_offer(&games, game_id, &tx).await;
+                }
             }
             _ => {}
         }
-
-        // handle_message(id.to_string(), msg, &mut games, ws);
     }
 
     // Disconnect Safely
-    handle_disconnect(id.to_string(), games)
+    handle_disconnect(id.to_string(), games, archive).await
 }
 
-fn handle_disconnect(id: String, mut games: HashMap<String, Option<State>>) {
-    games.remove(&id);
+// Structured Reply for an Applied Move, Carrying the Resulting Position and Status
+fn move_accepted(action: &str, game: &State, mv: Move, san: String) -> serde_json::Value {
+    let status = if game.outcome.is_some() {
+        "over"
+    } else if game.is_in_check(game.first_player_turn) {
+        "check"
+    } else {
+        "ongoing"
+    };
+
+    json!({
+        "action": action,
+        "accepted": true,
+        "move": mv.to_string(),
+        "san": san,
+        "fen": game.to_fen(),
+        "turn": if game.first_player_turn { "white" } else { "black" },
+        "status": status,
+        "hanging": {
+            "white": squares_json(game.hanging_pieces(true)),
+            "black": squares_json(game.hanging_pieces(false)),
+        },
+        "outcome": game.outcome.map(|outcome| json!({
+            "result": outcome.result(),
+            "reason": outcome.to_string(),
+        })),
+    })
+}
+
+fn player_to_move(game: &State) -> &Player {
+    player_of(game, game.first_player_turn)
+}
+
+fn player_of(game: &State, first_player: bool) -> &Player {
+    if first_player {
+        &game.white
+    } else {
+        &game.black
+    }
+}
+
+// The Side a Resignation or Draw Message Speaks For, Which May Never Be the Computer's
+fn acting_side(game: &State, side: Option<&str>) -> Result<bool, MoveError> {
+    let first_player = match side {
+        Some("white") => true,
+        Some("black") => false,
+        // While the Computer Thinks, the Message Comes From the Human Waiting on It
+        _ if player_to_move(game).ai => !game.first_player_turn,
+        _ => game.first_player_turn,
+    };
+
+    if player_of(game, first_player).ai {
+        return Err(MoveError::AiPlayer);
+    }
+
+    Ok(first_player)
+}
+
+// Searches Off the Async Runtime, Then Plays the Move Only if the Game Did Not Change Meanwhile
+async fn play_ai_move(games: &Games, game_id: &str, tx: &UnboundedSender<Message>) {
+    let snapshot = match games.read().await.get(game_id) {
+        Some(Some(Game { state: game, .. })) => game.clone(),
+        _ => return,
+    };
+
+    let player = player_to_move(&snapshot).clone();
+
+    if !player.ai {
+        return;
+    }
+
+    let hash = snapshot.hash;
+    let plies = snapshot.history.len();
+
+    let chosen = tokio::task::spawn_blocking(move || player.choose_move(&snapshot)).await;
+
+    let mv = match chosen {
+        Ok(Some(mv)) => mv,
+        _ => return,
+    };
+
+    let (reply, outcome) = match games.write().await.get_mut(game_id) {
+        Some(Some(Game { state: game, .. }))
+            if game.hash == hash && game.history.len() == plies =>
+        {
+            let san = game.move_to_san(mv);
+
+            match game.apply(mv) {
+                Ok(_) => (move_accepted("ai_move", game, mv, san), game.outcome),
+                Err(_) => return,
+            }
+        }
+        _ => return,
+    };
+
+    send(tx, reply.to_string());
+
+    if let Some(outcome) = outcome {
+        send(tx, format!("outcome: {} {}", outcome.result(), outcome));
+    }
+}
+
+// Weighs a Draw Offer Off the Async Runtime, Answering Only if the Game Did Not Change Meanwhile
+async fn answer_draw_offer(games: &Games, game_id: &str, tx: &UnboundedSender<Message>) {
+    let snapshot = match games.read().await.get(game_id) {
+        Some(Some(Game { state: game, .. })) => game.clone(),
+        _ => return,
+    };
+
+    let player = match snapshot.draw_offer {
+        Some(first_player) => player_of(&snapshot, !first_player).clone(),
+        None => return,
+    };
+
+    if !player.ai {
+        return;
+    }
+
+    let first_player = player.first_player;
+    let hash = snapshot.hash;
+    let plies = snapshot.history.len();
+
+    let accepted = tokio::task::spawn_blocking(move || player.accepts_draw(&snapshot))
+        .await
+        .unwrap_or(false);
+
+    let result = match games.write().await.get_mut(game_id) {
+        Some(Some(Game { state: game, .. }))
+            if game.hash == hash && game.history.len() == plies =>
+        {
+            if accepted {
+                game.accept_draw(first_player).map(Some)
+            } else {
+                game.decline_draw(first_player).map(|_| None)
+            }
+        }
+        _ => return,
+    };
+
+    match result {
+        Ok(Some(outcome)) => send(tx, format!("outcome: {} {}", outcome.result(), outcome)),
+        Ok(None) => send(tx, "draw: declined".to_string()),
+        Err(_) => {}
+    }
+}
+
+fn setup_rejected(error: &str) -> serde_json::Value {
+    json!({
+        "action": "setup",
+        "accepted": false,
+        "error": error,
+    })
+}
+
+fn squares_json(squares: Vec<Square>) -> Vec<String> {
+    squares.iter().map(Square::to_string).collect()
+}
+
+fn move_rejected(error: &str) -> serde_json::Value {
+    json!({
+        "action": "move",
+        "accepted": false,
+        "error": error,
+    })
+}
+
+fn send(tx: &UnboundedSender<Message>, text: String) {
+    if let Err(e) = tx.send(Message::text(text)) {
+        println!("websocket send error: {}", e);
+    }
+}
+
+// Accepts Either an `[x, y]` Pair or an Algebraic Name Like "e4"
+fn parse_coords(value: &serde_json::Value) -> Option<Square> {
+    if let Some(name) = value.as_str() {
+        return name.parse().ok();
+    }
+
+    let x = i32::try_from(value.get(0)?.as_i64()?).ok()?;
+    let y = i32::try_from(value.get(1)?.as_i64()?).ok()?;
+
+    Square::new(x, y)
+}
+
+async fn download_pgn(
+    id: String,
+    games: Games,
+    archive: Archive,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let live = match games.read().await.get(&id) {
+        Some(Some(game)) => Some(game_pgn(&id, game)),
+        _ => None,
+    };
+
+    let pgn = match live {
+        Some(pgn) => pgn,
+        None => match archive.read().await.get(&id) {
+            Some(archived) => archived.pgn.clone(),
+            None => return Err(warp::reject::not_found()),
+        },
+    };
+
+    let reply = warp::reply::with_header(pgn, "Content-Type", "application/x-chess-pgn");
+
+    Ok(warp::reply::with_header(
+        reply,
+        "Content-Disposition",
+        format!("attachment; filename=\"{}.pgn\"", id),
+    ))
+}
+
+fn game_pgn(id: &str, game: &Game) -> String {
+    let name = |player: &Player| if player.ai { "chess-engine" } else { "?" };
+
+    let mut pgn = Pgn::new();
+
+    pgn.set_tag("Event", "Casual Game");
+    pgn.set_tag("Site", "chess-engine");
+    pgn.set_tag("Date", &date_tag(game.started));
+    pgn.set_tag("White", name(&game.state.white));
+    pgn.set_tag("Black", name(&game.state.black));
+    pgn.set_tag("TimeControl", "-");
+    pgn.set_tag("GameId", id);
+
+    pgn.write(&game.state)
+}
+
+// Finished Games Stay Downloadable for a While; Unfinished Ones Go With Their Player
+async fn handle_disconnect(id: String, games: Games, archive: Archive) {
+    let game = games.write().await.remove(&id).flatten();
+
+    let mut archive = archive.write().await;
+
+    archive.retain(|_, x| x.stored.elapsed() < ARCHIVE_RETENTION);
+
+    if let Some(game) = game.filter(|x| x.state.outcome.is_some()) {
+        archive.insert(
+            id.clone(),
+            Archived {
+                pgn: game_pgn(&id, &game),
+                stored: Instant::now(),
+            },
+        );
+    }
 }
@@ -0,0 +1,88 @@
+use super::piece::{Piece, PieceType};
+use super::state::State;
+
+fn square_colour(piece: &Piece) -> i32 {
+    (piece.current_coords.file() + piece.current_coords.rank()) % 2
+}
+
+impl State {
+    // K v K, K+Minor v K and Bishop-Only Endings With Every Bishop on One Colour
+    pub fn is_insufficient_material(&self) -> bool {
+        let pieces: Vec<Piece> = self
+            .pieces(true)
+            .into_iter()
+            .chain(self.pieces(false))
+            .filter(|x| x.piece_type != PieceType::King)
+            .collect();
+
+        if pieces.is_empty() {
+            return true;
+        }
+
+        if pieces.len() == 1 {
+            return matches!(pieces[0].piece_type, PieceType::Knight | PieceType::Bishop);
+        }
+
+        pieces.iter().all(|x| x.piece_type == PieceType::Bishop)
+            && pieces
+                .iter()
+                .all(|x| square_colour(x) == square_colour(&pieces[0]))
+    }
+
+    // Whether the Given Side Could Ever Deliver Mate, Even With the Opponent's Help
+    pub fn can_checkmate(&self, first_player: bool) -> bool {
+        let pieces: Vec<Piece> = self
+            .pieces(first_player)
+            .into_iter()
+            .filter(|x| x.piece_type != PieceType::King)
+            .collect();
+
+        if pieces.iter().any(|x| {
+            matches!(
+                x.piece_type,
+                PieceType::Pawn | PieceType::Rook | PieceType::Queen
+            )
+        }) {
+            return true;
+        }
+
+        let knights = pieces
+            .iter()
+            .filter(|x| x.piece_type == PieceType::Knight)
+            .count();
+
+        let bishops: Vec<&Piece> = pieces
+            .iter()
+            .filter(|x| x.piece_type == PieceType::Bishop)
+            .collect();
+
+        let enemy_pieces: Vec<Piece> = self
+            .pieces(!first_player)
+            .into_iter()
+            .filter(|x| x.piece_type != PieceType::King)
+            .collect();
+
+        match (knights, bishops.len()) {
+            (0, 0) => false,
+
+            // A Lone Knight Needs an Enemy Piece to Hem the King In
+            (1, 0) => !enemy_pieces.is_empty(),
+
+            // Same-Coloured Bishops Need a Blocker That Can Stand on the Other Colour
+            (0, _) => {
+                let colour = square_colour(bishops[0]);
+
+                bishops.iter().any(|x| square_colour(x) != colour)
+                    || enemy_pieces
+                        .iter()
+                        .any(|x| x.piece_type != PieceType::Bishop || square_colour(x) != colour)
+            }
+
+            _ => true,
+        }
+    }
+
+    pub fn is_dead_position(&self) -> bool {
+        !self.can_checkmate(true) && !self.can_checkmate(false)
+    }
+}
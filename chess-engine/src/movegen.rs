@@ -0,0 +1,246 @@
+use super::bitboard::{
+    between, bishop_attacks, bit, king_attacks, knight_attacks, line, lsb, pawn_attacks,
+    queen_attacks, rook_attacks, squares, Bitboard, RANK_1, RANK_8,
+};
+use super::moves::{Move, MoveKind};
+use super::piece::PieceType;
+use super::square::Square;
+use super::state::State;
+
+const PROMOTIONS: [PieceType; 4] = [
+    PieceType::Queen,
+    PieceType::Rook,
+    PieceType::Bishop,
+    PieceType::Knight,
+];
+
+impl State {
+    pub fn legal_moves(&self) -> Vec<Move> {
+        let mut moves = Vec::with_capacity(64);
+        self.generate_moves(&mut moves);
+        moves
+    }
+
+    pub fn generate_moves(&self, moves: &mut Vec<Move>) {
+        let first_player = self.first_player_turn;
+        let board = &self.board;
+
+        let own = board.colour(first_player);
+        let occupied = board.occupied;
+
+        let king_square = self.king_square(first_player);
+        let checkers = self.attackers_to(king_square, !first_player, occupied);
+
+        // King Moves Are Tested With the King Lifted so It Cannot Hide Behind Itself
+        let without_king = occupied & !bit(king_square);
+
+        for to in squares(king_attacks(king_square) & !own) {
+            if self.attackers_to(to, !first_player, without_king) == 0 {
+                self.push_moves(moves, PieceType::King, king_square, to, MoveKind::Normal);
+            }
+        }
+
+        // Only the King Can Answer a Double Check
+        if checkers.count_ones() > 1 {
+            return;
+        }
+
+        let check_mask = if checkers != 0 {
+            checkers | between(king_square, lsb(checkers))
+        } else {
+            !0
+        };
+
+        let mut pinned: Bitboard = 0;
+
+        for (pinned_square, _) in self.pinners(first_player) {
+            pinned |= bit(pinned_square);
+        }
+
+        let targets = !own & check_mask;
+
+        for piece_type in [
+            PieceType::Queen,
+            PieceType::Rook,
+            PieceType::Bishop,
+            PieceType::Knight,
+        ] {
+            for from in squares(board.pieces_of(piece_type, first_player)) {
+                let mut attacks = match piece_type {
+                    PieceType::Queen => queen_attacks(from, occupied),
+                    PieceType::Rook => rook_attacks(from, occupied),
+                    PieceType::Bishop => bishop_attacks(from, occupied),
+                    _ => knight_attacks(from),
+                } & targets;
+
+                if pinned & bit(from) != 0 {
+                    attacks &= line(king_square, from);
+                }
+
+                for to in squares(attacks) {
+                    self.push_moves(moves, piece_type, from, to, MoveKind::Normal);
+                }
+            }
+        }
+
+        self.generate_pawn_moves(moves, king_square, check_mask, pinned);
+
+        if checkers == 0 {
+            self.generate_castling(moves, king_square);
+        }
+    }
+
+    fn generate_pawn_moves(
+        &self,
+        moves: &mut Vec<Move>,
+        king_square: Square,
+        check_mask: Bitboard,
+        pinned: Bitboard,
+    ) {
+        let first_player = self.first_player_turn;
+        let board = &self.board;
+
+        let enemy = board.colour(!first_player);
+        let empty = !board.occupied;
+        let forward = if first_player { 1 } else { -1 };
+        let start_rank = if first_player { 2 } else { 7 };
+
+        for from in squares(board.pieces_of(PieceType::Pawn, first_player)) {
+            let allowed = if pinned & bit(from) != 0 {
+                check_mask & line(king_square, from)
+            } else {
+                check_mask
+            };
+
+            // Pushes, Where There Is Always a Square Ahead as Pawns Never Rest on the Last Rank
+            let single = from.offset(0, forward).unwrap();
+
+            if empty & bit(single) != 0 {
+                if allowed & bit(single) != 0 {
+                    self.push_moves(moves, PieceType::Pawn, from, single, MoveKind::Normal);
+                }
+
+                if from.rank() == start_rank {
+                    let double = single.offset(0, forward).unwrap();
+
+                    if empty & allowed & bit(double) != 0 {
+                        self.push_moves(
+                            moves,
+                            PieceType::Pawn,
+                            from,
+                            double,
+                            MoveKind::DoublePawnPush,
+                        );
+                    }
+                }
+            }
+
+            // Captures
+            let attacks = pawn_attacks(from, first_player);
+
+            for to in squares(attacks & enemy & allowed) {
+                self.push_moves(moves, PieceType::Pawn, from, to, MoveKind::Normal);
+            }
+
+            if let Some(to) = self.en_passant {
+                if attacks & bit(to) != 0 && self.is_legal_en_passant(from, to, king_square) {
+                    self.push_moves(moves, PieceType::Pawn, from, to, MoveKind::EnPassant);
+                }
+            }
+        }
+    }
+
+    // Replays the Capture on the Occupancy, Catching Pins Through Both Pawns
+    fn is_legal_en_passant(&self, from: Square, to: Square, king_square: Square) -> bool {
+        let first_player = self.first_player_turn;
+        let captured = Square::new(to.file(), from.rank()).unwrap();
+
+        let occupied = (self.board.occupied & !bit(from) & !bit(captured)) | bit(to);
+        let attackers = self.attackers_to(king_square, !first_player, occupied) & !bit(captured);
+
+        attackers == 0
+    }
+
+    fn generate_castling(&self, moves: &mut Vec<Move>, king_square: Square) {
+        let first_player = self.first_player_turn;
+        let (kingside, queenside) = self.castling.get(first_player);
+
+        let (home, sides) = if first_player {
+            (
+                Square::E1,
+                [
+                    (kingside, Square::H1, Square::G1),
+                    (queenside, Square::A1, Square::C1),
+                ],
+            )
+        } else {
+            (
+                Square::E8,
+                [
+                    (kingside, Square::H8, Square::G8),
+                    (queenside, Square::A8, Square::C8),
+                ],
+            )
+        };
+
+        if king_square != home {
+            return;
+        }
+
+        let rooks = self.board.pieces_of(PieceType::Rook, first_player);
+
+        for (allowed, rook_square, destination) in sides {
+            if !allowed || rooks & bit(rook_square) == 0 {
+                continue;
+            }
+
+            // Everything Between King and Rook Must Be Empty, but Only the King's Path Must Be Safe
+            let empty = between(king_square, rook_square) & self.board.occupied == 0;
+
+            let safe = squares(between(king_square, destination) | bit(destination))
+                .all(|x| self.attackers_to(x, !first_player, self.board.occupied) == 0);
+
+            if empty && safe {
+                self.push_moves(
+                    moves,
+                    PieceType::King,
+                    king_square,
+                    destination,
+                    MoveKind::Castling,
+                );
+            }
+        }
+    }
+
+    fn push_moves(
+        &self,
+        moves: &mut Vec<Move>,
+        piece_type: PieceType,
+        from: Square,
+        to: Square,
+        kind: MoveKind,
+    ) {
+        let captured = match kind {
+            MoveKind::EnPassant => Some(PieceType::Pawn),
+            _ => self.board.piece_at(to).map(|(piece_type, _)| piece_type),
+        };
+
+        let mv = Move {
+            current_coords: from,
+            destination: to,
+            piece_type,
+            captured,
+            promotion: None,
+            kind,
+        };
+
+        if piece_type == PieceType::Pawn && bit(to) & (RANK_1 | RANK_8) != 0 {
+            moves.extend(PROMOTIONS.iter().map(|&piece_type| Move {
+                promotion: Some(piece_type),
+                ..mv
+            }));
+        } else {
+            moves.push(mv);
+        }
+    }
+}
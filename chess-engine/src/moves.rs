@@ -0,0 +1,119 @@
+use super::outcome::GameOutcome;
+use super::piece::{Piece, PieceType};
+use super::square::Square;
+use super::state::CastlingRights;
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MoveKind {
+    Normal,
+    DoublePawnPush,
+    EnPassant,
+    Castling,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Move {
+    pub current_coords: Square,
+    pub destination: Square,
+    pub piece_type: PieceType,
+    pub captured: Option<PieceType>,
+    pub promotion: Option<PieceType>,
+    pub kind: MoveKind,
+}
+
+impl Move {
+    pub fn is_capture(&self) -> bool {
+        self.captured.is_some()
+    }
+
+    // Square of the Captured Piece, Which Differs From the Destination for En Passant
+    pub fn capture_coords(&self) -> Square {
+        if self.kind == MoveKind::EnPassant {
+            Square::new(self.destination.file(), self.current_coords.rank()).unwrap()
+        } else {
+            self.destination
+        }
+    }
+
+    // Rook Origin and Destination for Castling Moves
+    pub fn castling_rook(&self) -> Option<(Square, Square)> {
+        if self.kind != MoveKind::Castling {
+            return None;
+        }
+
+        match self.destination {
+            Square::G1 => Some((Square::H1, Square::F1)),
+            Square::C1 => Some((Square::A1, Square::D1)),
+            Square::G8 => Some((Square::H8, Square::F8)),
+            Square::C8 => Some((Square::A8, Square::D8)),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let promotion = match self.promotion {
+            Some(PieceType::Queen) => "q",
+            Some(PieceType::Rook) => "r",
+            Some(PieceType::Bishop) => "b",
+            Some(PieceType::Knight) => "n",
+            _ => "",
+        };
+
+        write!(
+            f,
+            "{}{}{}",
+            self.current_coords, self.destination, promotion
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Undo {
+    pub mv: Move,
+    pub first_player: bool,
+    pub captured: Option<Piece>,
+    pub castling: CastlingRights,
+    pub en_passant: Option<Square>,
+    pub halfmove_clock: u32,
+    pub fullmove_number: u32,
+    pub hash: u64,
+    pub outcome: Option<GameOutcome>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MoveError {
+    GameOver,
+    OutOfTurn,
+    AiPlayer,
+    OffBoard,
+    Malformed,
+    NoPiece(Square),
+    Illegal(Square, Square),
+    InvalidPromotion,
+    NoDrawToClaim,
+    NoDrawOffer,
+}
+
+impl fmt::Display for MoveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MoveError::GameOver => write!(f, "the game is already over"),
+            MoveError::OutOfTurn => write!(f, "it is not this side's turn to move"),
+            MoveError::AiPlayer => write!(f, "this side is played by the computer"),
+            MoveError::OffBoard => write!(f, "coordinates are off the board"),
+            MoveError::Malformed => write!(f, "moves are written like e2e4 or e7e8q"),
+            MoveError::NoPiece(square) => write!(f, "no piece to move on {}", square),
+            MoveError::Illegal(current_coords, destination) => {
+                write!(f, "{}{} is not a legal move", current_coords, destination)
+            }
+            MoveError::InvalidPromotion => write!(f, "pawns may only promote to Q, R, B or N"),
+            MoveError::NoDrawToClaim => write!(f, "no draw can be claimed in this position"),
+            MoveError::NoDrawOffer => write!(f, "the other side has not offered a draw"),
+        }
+    }
+}
+
+impl std::error::Error for MoveError {}
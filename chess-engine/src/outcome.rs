@@ -0,0 +1,77 @@
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GameOutcome {
+    Checkmate { first_player_wins: bool },
+    Resignation { first_player_wins: bool },
+    Timeout { first_player_wins: bool },
+    Stalemate,
+    InsufficientMaterial,
+    TimeoutVsInsufficientMaterial,
+    FiftyMoveRule,
+    SeventyFiveMoveRule,
+    ThreefoldRepetition,
+    FivefoldRepetition,
+    Agreement,
+}
+
+impl GameOutcome {
+    // `Some(true)` When White Wins, `None` for Draws
+    pub fn winner(&self) -> Option<bool> {
+        match self {
+            GameOutcome::Checkmate { first_player_wins }
+            | GameOutcome::Resignation { first_player_wins }
+            | GameOutcome::Timeout { first_player_wins } => Some(*first_player_wins),
+            _ => None,
+        }
+    }
+
+    // Ended by a Player or the Clock Rather Than by the Position on the Board
+    pub fn is_declared(&self) -> bool {
+        matches!(
+            self,
+            GameOutcome::Resignation { .. }
+                | GameOutcome::Timeout { .. }
+                | GameOutcome::TimeoutVsInsufficientMaterial
+                | GameOutcome::FiftyMoveRule
+                | GameOutcome::ThreefoldRepetition
+                | GameOutcome::Agreement
+        )
+    }
+
+    pub fn result(&self) -> &'static str {
+        match self.winner() {
+            Some(true) => "1-0",
+            Some(false) => "0-1",
+            None => "1/2-1/2",
+        }
+    }
+}
+
+impl fmt::Display for GameOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let side = |first_player_wins: &bool| if *first_player_wins { "white" } else { "black" };
+
+        match self {
+            GameOutcome::Checkmate { first_player_wins } => {
+                write!(f, "{} wins by checkmate", side(first_player_wins))
+            }
+            GameOutcome::Resignation { first_player_wins } => {
+                write!(f, "{} wins by resignation", side(first_player_wins))
+            }
+            GameOutcome::Timeout { first_player_wins } => {
+                write!(f, "{} wins on time", side(first_player_wins))
+            }
+            GameOutcome::Stalemate => write!(f, "draw by stalemate"),
+            GameOutcome::InsufficientMaterial => write!(f, "draw by insufficient material"),
+            GameOutcome::TimeoutVsInsufficientMaterial => {
+                write!(f, "draw by timeout vs insufficient material")
+            }
+            GameOutcome::FiftyMoveRule => write!(f, "draw by the fifty-move rule"),
+            GameOutcome::SeventyFiveMoveRule => write!(f, "draw by the seventy-five-move rule"),
+            GameOutcome::ThreefoldRepetition => write!(f, "draw by threefold repetition"),
+            GameOutcome::FivefoldRepetition => write!(f, "draw by fivefold repetition"),
+            GameOutcome::Agreement => write!(f, "draw by agreement"),
+        }
+    }
+}
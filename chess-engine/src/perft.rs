@@ -0,0 +1,43 @@
+use super::moves::Move;
+use super::state::State;
+
+impl State {
+    pub fn perft(&mut self, depth: u32) -> u64 {
+        if depth == 0 {
+            return 1;
+        }
+
+        let moves = self.legal_moves();
+
+        if depth == 1 {
+            return moves.len() as u64;
+        }
+
+        let mut nodes = 0;
+
+        for mv in moves {
+            self.make_move(mv);
+            nodes += self.perft(depth - 1);
+            self.unmake_move();
+        }
+
+        nodes
+    }
+
+    // Node Counts per Root Move, for Comparing Against a Reference Engine
+    pub fn divide(&mut self, depth: u32) -> Vec<(Move, u64)> {
+        let mut counts = Vec::new();
+
+        if depth == 0 {
+            return counts;
+        }
+
+        for mv in self.legal_moves() {
+            self.make_move(mv);
+            counts.push((mv, self.perft(depth - 1)));
+            self.unmake_move();
+        }
+
+        counts
+    }
+}
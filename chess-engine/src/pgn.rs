@@ -0,0 +1,533 @@
+use super::fen::{FenError, START_FEN};
+use super::state::State;
+use std::collections::HashMap;
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// Export Lines Are Kept Under 80 Characters, as the PGN Standard Asks
+const LINE_WIDTH: usize = 79;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Annotation {
+    pub comment: Option<String>,
+    pub clock: Option<Duration>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Pgn {
+    pub tags: Vec<(String, String)>,
+    pub annotations: HashMap<usize, Annotation>,
+}
+
+impl Default for Pgn {
+    fn default() -> Pgn {
+        Pgn::new()
+    }
+}
+
+impl Pgn {
+    // Starts From the Seven Tag Roster With Every Value Unknown
+    pub fn new() -> Pgn {
+        let tags = [
+            ("Event", "?"),
+            ("Site", "?"),
+            ("Date", "????.??.??"),
+            ("Round", "?"),
+            ("White", "?"),
+            ("Black", "?"),
+            ("Result", "*"),
+        ];
+
+        Pgn {
+            tags: tags
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+            annotations: HashMap::new(),
+        }
+    }
+
+    // Replaces an Existing Tag in Place, or Appends a New One After the Roster
+    pub fn set_tag(&mut self, name: &str, value: &str) {
+        match self.tags.iter_mut().find(|(x, _)| x == name) {
+            Some(tag) => tag.1 = value.to_string(),
+            None => self.tags.push((name.to_string(), value.to_string())),
+        }
+    }
+
+    // Annotations Are Keyed by Ply, Counting From Zero for the First Recorded Move
+    pub fn comment(&mut self, ply: usize, comment: &str) {
+        self.annotations.entry(ply).or_default().comment = Some(comment.to_string());
+    }
+
+    pub fn clock(&mut self, ply: usize, remaining: Duration) {
+        self.annotations.entry(ply).or_default().clock = Some(remaining);
+    }
+
+    pub fn write(&self, state: &State) -> String {
+        let result = state.outcome.map_or("*", |x| x.result());
+
+        // Rewind to the Position the Game Started From
+        let mut replay = state.clone();
+        while replay.unmake_move().is_some() {}
+
+        let mut header = self.clone();
+        let start_fen = replay.to_fen();
+
+        header.set_tag("Result", result);
+
+        if start_fen != START_FEN {
+            header.set_tag("SetUp", "1");
+            header.set_tag("FEN", &start_fen);
+        }
+
+        let mut pgn: String = header
+            .tags
+            .iter()
+            .map(|(name, value)| format!("[{} \"{}\"]\n", name, escape_tag(value)))
+            .collect();
+
+        pgn.push('\n');
+
+        // Movetext
+        let mut tokens = Vec::new();
+        let mut needs_number = true;
+
+        for (ply, undo) in state.history.iter().enumerate() {
+            let number = replay.fullmove_number;
+
+            if replay.first_player_turn {
+                tokens.push(format!("{}.", number));
+            } else if needs_number {
+                tokens.push(format!("{}...", number));
+            }
+
+            tokens.push(replay.move_to_san(undo.mv));
+            replay.make_move(undo.mv);
+
+            needs_number = false;
+
+            if let Some(annotation) = self.annotations.get(&ply) {
+                let mut parts = Vec::new();
+
+                if let Some(comment) = &annotation.comment {
+                    parts.push(comment.replace('}', ")"));
+                }
+
+                if let Some(clock) = annotation.clock {
+                    parts.push(format!("[%clk {}]", format_clock(clock)));
+                }
+
+                if !parts.is_empty() {
+                    tokens.push(format!("{{{}}}", parts.join(" ")));
+
+                    // Black's Reply Gets Its Number Again After a Comment
+                    needs_number = true;
+                }
+            }
+        }
+
+        tokens.push(result.to_string());
+
+        let mut line = String::new();
+
+        for token in tokens {
+            if !line.is_empty() && line.len() + 1 + token.len() > LINE_WIDTH {
+                pgn.push_str(&line);
+                pgn.push('\n');
+                line.clear();
+            }
+
+            if !line.is_empty() {
+                line.push(' ');
+            }
+
+            line.push_str(&token);
+        }
+
+        pgn.push_str(&line);
+        pgn.push('\n');
+
+        pgn
+    }
+}
+
+impl State {
+    pub fn to_pgn(&self) -> String {
+        Pgn::new().write(self)
+    }
+}
+
+fn escape_tag(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn format_clock(clock: Duration) -> String {
+    let seconds = clock.as_secs();
+
+    format!(
+        "{}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+// "YYYY.MM.DD" in UTC, for the Date Tag
+pub fn date_tag(time: SystemTime) -> String {
+    let days = time
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |x| x.as_secs() / 86400) as i64;
+
+    // Civil From Days, Counting Eras of 400 Years From 0000-03-01
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let day_of_era = z.rem_euclid(146097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!("{:04}.{:02}.{:02}", year, month, day)
+}
+
+#[derive(Debug, PartialEq)]
+pub enum PgnError {
+    Syntax {
+        line: usize,
+        column: usize,
+        message: &'static str,
+    },
+    Fen(FenError),
+    IllegalMove {
+        line: usize,
+        column: usize,
+        mv: String,
+        reason: String,
+    },
+}
+
+impl fmt::Display for PgnError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PgnError::Syntax {
+                line,
+                column,
+                message,
+            } => write!(f, "line {}, column {}: {}", line, column, message),
+            PgnError::Fen(e) => write!(f, "invalid FEN tag: {}", e),
+            PgnError::IllegalMove {
+                line,
+                column,
+                mv,
+                reason,
+            } => write!(f, "line {}, column {}: {} ({})", line, column, mv, reason),
+        }
+    }
+}
+
+impl std::error::Error for PgnError {}
+
+// A Move as Written in the Source, With Where It Was Found
+#[derive(Clone, Debug, PartialEq)]
+pub struct PgnMove {
+    pub san: String,
+    pub line: usize,
+    pub column: usize,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PgnGame {
+    pub tags: Vec<(String, String)>,
+    pub moves: Vec<PgnMove>,
+    pub result: Option<String>,
+}
+
+impl PgnGame {
+    pub fn tag(&self, name: &str) -> Option<&str> {
+        self.tags
+            .iter()
+            .find(|(x, _)| x == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    // Plays the Main Line Through Move Validation, Stopping at the First Illegal Move
+    pub fn replay(&self) -> Result<State, PgnError> {
+        let mut state = match self.tag("FEN") {
+            Some(fen) if self.tag("SetUp") != Some("0") => {
+                State::from_fen(fen).map_err(PgnError::Fen)?
+            }
+            _ => State::new(false),
+        };
+
+        for pgn_move in &self.moves {
+            let number = if state.first_player_turn {
+                format!("{}.", state.fullmove_number)
+            } else {
+                format!("{}...", state.fullmove_number)
+            };
+
+            let illegal = |reason: String| PgnError::IllegalMove {
+                line: pgn_move.line,
+                column: pgn_move.column,
+                mv: format!("{} {}", number, pgn_move.san),
+                reason,
+            };
+
+            let mv = state
+                .parse_san(&pgn_move.san)
+                .map_err(|e| illegal(e.to_string()))?;
+
+            state.apply(mv).map_err(|e| illegal(e.to_string()))?;
+        }
+
+        Ok(state)
+    }
+}
+
+struct Lexer {
+    chars: Vec<char>,
+    position: usize,
+    line: usize,
+    column: usize,
+}
+
+impl Lexer {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+
+        self.position += 1;
+
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+
+        Some(c)
+    }
+
+    fn error(&self, line: usize, column: usize, message: &'static str) -> PgnError {
+        PgnError::Syntax {
+            line,
+            column,
+            message,
+        }
+    }
+
+    fn skip_line(&mut self) {
+        while self.next().is_some_and(|c| c != '\n') {}
+    }
+
+    // Reads a Brace Comment, Which May Span Lines but Not Nest
+    fn read_comment(&mut self) -> Result<String, PgnError> {
+        let (line, column) = (self.line, self.column);
+        let mut text = String::new();
+
+        self.next();
+
+        loop {
+            match self.next() {
+                Some('}') => return Ok(text),
+                Some(c) => text.push(c),
+                None => return Err(self.error(line, column, "unterminated comment")),
+            }
+        }
+    }
+
+    fn read_tag(&mut self) -> Result<(String, String), PgnError> {
+        let (line, column) = (self.line, self.column);
+        let malformed = self.error(line, column, "malformed tag pair");
+
+        self.next();
+
+        let mut name = String::new();
+
+        while let Some(c) = self.peek().filter(|c| c.is_alphanumeric() || *c == '_') {
+            name.push(c);
+            self.next();
+        }
+
+        while self.peek().is_some_and(|c| c == ' ' || c == '\t') {
+            self.next();
+        }
+
+        if name.is_empty() || self.next() != Some('"') {
+            return Err(malformed);
+        }
+
+        let mut value = String::new();
+
+        loop {
+            match self.next() {
+                Some('\\') => match self.next() {
+                    Some(c) => value.push(c),
+                    None => return Err(malformed),
+                },
+                Some('"') => break,
+                Some('\n') | None => return Err(malformed),
+                Some(c) => value.push(c),
+            }
+        }
+
+        while self.peek().is_some_and(|c| c == ' ' || c == '\t') {
+            self.next();
+        }
+
+        if self.next() != Some(']') {
+            return Err(malformed);
+        }
+
+        Ok((name, value))
+    }
+
+    fn read_symbol(&mut self) -> String {
+        let mut symbol = String::new();
+
+        while let Some(c) = self
+            .peek()
+            .filter(|c| !c.is_whitespace() && !"{}()[];$".contains(*c))
+        {
+            symbol.push(c);
+            self.next();
+        }
+
+        symbol
+    }
+}
+
+fn is_result(symbol: &str) -> bool {
+    matches!(symbol, "1-0" | "0-1" | "1/2-1/2" | "*")
+}
+
+// Splits a File Into Games, Keeping Only Main-Line Moves
+pub fn parse_pgn(text: &str) -> Result<Vec<PgnGame>, PgnError> {
+    let mut lexer = Lexer {
+        chars: text.chars().collect(),
+        position: 0,
+        line: 1,
+        column: 1,
+    };
+
+    let mut games = Vec::new();
+    let mut game = PgnGame::default();
+    let mut depth = 0;
+
+    while let Some(c) = lexer.peek() {
+        let (line, column) = (lexer.line, lexer.column);
+
+        match c {
+            _ if c.is_whitespace() => {
+                lexer.next();
+            }
+
+            // Escape Lines and Rest-of-Line Comments
+            '%' if column == 1 => lexer.skip_line(),
+            ';' => lexer.skip_line(),
+
+            '{' => {
+                lexer.read_comment()?;
+            }
+
+            '(' => {
+                lexer.next();
+                depth += 1;
+            }
+            ')' => {
+                if depth == 0 {
+                    return Err(lexer.error(line, column, "unbalanced ')'"));
+                }
+
+                lexer.next();
+                depth -= 1;
+            }
+
+            '[' if depth > 0 => {
+                lexer.next();
+            }
+            '[' => {
+                // Tags After Movetext Start the Next Game, Even Without a Result
+                if !game.moves.is_empty() {
+                    games.push(std::mem::take(&mut game));
+                }
+
+                let tag = lexer.read_tag()?;
+                game.tags.push(tag);
+            }
+
+            // Numeric Annotation Glyphs
+            '$' => {
+                lexer.next();
+
+                while lexer.peek().is_some_and(|c| c.is_ascii_digit()) {
+                    lexer.next();
+                }
+            }
+
+            _ => {
+                let symbol = lexer.read_symbol();
+
+                if symbol.is_empty() {
+                    return Err(lexer.error(line, column, "unexpected character"));
+                }
+
+                if depth > 0 {
+                    continue;
+                }
+
+                if is_result(&symbol) {
+                    game.result = Some(symbol);
+                    games.push(std::mem::take(&mut game));
+                    continue;
+                }
+
+                // Move Numbers, Possibly Run Together With the Move as in "12.Nf3"
+                let digits = symbol.chars().take_while(|c| c.is_ascii_digit()).count();
+                let dots = symbol[digits..].chars().take_while(|&c| c == '.').count();
+
+                if dots > 0 || digits == symbol.len() {
+                    let san = &symbol[digits + dots..];
+
+                    if !san.is_empty() {
+                        game.moves.push(PgnMove {
+                            san: san.to_string(),
+                            line,
+                            column: column + digits + dots,
+                        });
+                    }
+
+                    continue;
+                }
+
+                game.moves.push(PgnMove {
+                    san: symbol,
+                    line,
+                    column,
+                });
+            }
+        }
+    }
+
+    if depth > 0 {
+        return Err(lexer.error(lexer.line, lexer.column, "unterminated variation"));
+    }
+
+    if !game.tags.is_empty() || !game.moves.is_empty() {
+        games.push(game);
+    }
+
+    Ok(games)
+}
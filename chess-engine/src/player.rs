@@ -1,34 +1,111 @@
-use crate::state::STATE;
+use super::difficulty::Difficulty;
+use super::moves::{Move, MoveError, Undo};
+use super::piece::PieceType;
+use super::square::Square;
+use super::state::State;
+use super::tt::{TranspositionTable, DEFAULT_SIZE_MB};
+use std::sync::{Arc, Mutex};
 
-use super::piece::Piece;
-use super::utils::is_within_board_limits;
+// Largest Advantage, in Centipawns, a Computer Player Gives Up by Agreeing to a Draw
+const DRAW_MARGIN: i32 = 25;
 
 #[derive(Clone)]
 pub struct Player {
     pub first_player: bool,
     pub ai: bool,
-    pub pieces: Vec<Piece>,
-    pub king_coord: (i32, i32),
+    pub difficulty: Difficulty,
+    // Shared by Every Copy of the Player so What One Search Learns Carries Over to the Next Move
+    pub table: Option<Arc<Mutex<TranspositionTable>>>,
 }
 
 impl Player {
-    fn move_piece(self, current_coords: Option<(i32, i32)>, destination: Option<(i32, i32)>) {
-        if self.ai {
-        } else {
-            let map = unsafe { &STATE.board };
+    pub fn move_piece(
+        &self,
+        state: &mut State,
+        current_coords: Option<Square>,
+        destination: Option<Square>,
+        promotion: Option<PieceType>,
+    ) -> Result<Undo, MoveError> {
+        self.check_turn(state)?;
+
+        let current_coords = current_coords.ok_or(MoveError::OffBoard)?;
+        let destination = destination.ok_or(MoveError::OffBoard)?;
+
+        let mv = state.find_move(current_coords, destination, promotion)?;
 
-            let (x, y) = current_coords.unwrap();
-            let (dest_x, dest_y) = destination.unwrap();
+        self.play(state, mv)
+    }
+
+    // Play an Already Resolved Move on This Player's Behalf
+    pub fn play(&self, state: &mut State, mv: Move) -> Result<Undo, MoveError> {
+        self.check_turn(state)?;
 
-            if is_within_board_limits(x, y) && is_within_board_limits(dest_x, dest_y) {
-                let space = map.get(&current_coords.unwrap()).unwrap();
+        state.apply(mv)
+    }
 
-                if !space.occupied.is_none() {
-                    let mut p = space.occupied.clone().unwrap();
+    // The Engine's Choice for a Computer Player Whose Turn It Is
+    pub fn choose_move(&self, state: &State) -> Option<Move> {
+        if !self.ai || state.first_player_turn != self.first_player || state.outcome.is_some() {
+            return None;
+        }
 
-                    p.navigate(destination.unwrap(), self.first_player);
-                }
+        let strength = self.difficulty.strength();
+
+        self.with_table(|table| {
+            if strength.is_full_strength() {
+                return state.search_with_table(strength.limits(), table).best_move;
             }
+
+            strength.pick(&state.rank_moves(strength.limits(), table))
+        })
+    }
+
+    // A Computer Player Takes a Draw Unless Its Search Says It Stands Clearly Better
+    pub fn accepts_draw(&self, state: &State) -> bool {
+        if !self.ai || state.outcome.is_some() {
+            return false;
+        }
+
+        let limits = self.difficulty.strength().limits();
+        let score = self.with_table(|table| state.search_with_table(limits, table).score);
+
+        let score = if state.first_player_turn == self.first_player {
+            score
+        } else {
+            -score
+        };
+
+        score <= DRAW_MARGIN
+    }
+
+    // Searches With the Player's Own Table, or a Throwaway One When It Has None
+    fn with_table<T>(&self, search: impl FnOnce(&mut TranspositionTable) -> T) -> T {
+        let mut fresh = None;
+
+        let mut shared = self.table.as_ref().map(|x| x.lock().unwrap());
+
+        let table = match shared.as_deref_mut() {
+            Some(table) => table,
+            None => fresh.insert(TranspositionTable::new(DEFAULT_SIZE_MB)),
+        };
+
+        search(table)
+    }
+
+    // Gives the Player Its Own Table of the Given Size, Replacing Any Earlier One
+    pub fn set_table_size(&mut self, size_mb: usize) {
+        self.table = Some(Arc::new(Mutex::new(TranspositionTable::new(size_mb))));
+    }
+
+    fn check_turn(&self, state: &State) -> Result<(), MoveError> {
+        if self.ai {
+            return Err(MoveError::AiPlayer);
         }
+
+        if state.first_player_turn != self.first_player {
+            return Err(MoveError::OutOfTurn);
+        }
+
+        Ok(())
     }
 }
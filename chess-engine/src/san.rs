@@ -0,0 +1,211 @@
+use super::moves::{Move, MoveKind};
+use super::piece::PieceType;
+use super::square::Square;
+use super::state::State;
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub enum SanError {
+    Invalid(String),
+    Illegal(String),
+    Ambiguous(String),
+}
+
+impl fmt::Display for SanError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SanError::Invalid(san) => write!(f, "'{}' is not a valid move in SAN", san),
+            SanError::Illegal(san) => write!(f, "{} is not a legal move", san),
+            SanError::Ambiguous(san) => write!(f, "{} could be more than one move", san),
+        }
+    }
+}
+
+impl std::error::Error for SanError {}
+
+fn piece_letter(piece_type: PieceType) -> Option<char> {
+    match piece_type {
+        PieceType::King => Some('K'),
+        PieceType::Queen => Some('Q'),
+        PieceType::Rook => Some('R'),
+        PieceType::Bishop => Some('B'),
+        PieceType::Knight => Some('N'),
+        PieceType::Pawn => None,
+    }
+}
+
+fn piece_from_letter(c: char) -> Option<PieceType> {
+    match c {
+        'K' => Some(PieceType::King),
+        'Q' => Some(PieceType::Queen),
+        'R' => Some(PieceType::Rook),
+        'B' => Some(PieceType::Bishop),
+        'N' => Some(PieceType::Knight),
+        _ => None,
+    }
+}
+
+impl State {
+    // Writes a Legal Move for the Side to Move, e.g. "Nbd7", "exd5", "e8=Q+" or "O-O-O#"
+    pub fn move_to_san(&self, mv: Move) -> String {
+        let mut san = String::new();
+
+        if let Some((_, rook_destination)) = mv.castling_rook() {
+            san.push_str(if rook_destination.file() == 6 {
+                "O-O"
+            } else {
+                "O-O-O"
+            });
+        } else {
+            match piece_letter(mv.piece_type) {
+                Some(letter) => {
+                    san.push(letter);
+                    san.push_str(&self.disambiguation(mv));
+                }
+                None if mv.is_capture() => {
+                    san.push_str(&mv.current_coords.to_string()[..1]);
+                }
+                None => {}
+            }
+
+            if mv.is_capture() {
+                san.push('x');
+            }
+
+            san.push_str(&mv.destination.to_string());
+
+            if let Some(letter) = mv.promotion.and_then(piece_letter) {
+                san.push('=');
+                san.push(letter);
+            }
+        }
+
+        // Check and Mate Suffixes
+        let mut after = self.clone();
+        after.make_move(mv);
+
+        if after.is_in_check(after.first_player_turn) {
+            san.push(if after.legal_moves().is_empty() {
+                '#'
+            } else {
+                '+'
+            });
+        }
+
+        san
+    }
+
+    // File, Rank or Both of the Origin When Another Piece of the Same Type Could Also Move There
+    fn disambiguation(&self, mv: Move) -> String {
+        let rivals: Vec<Square> = self
+            .legal_moves()
+            .into_iter()
+            .filter(|x| {
+                x.piece_type == mv.piece_type
+                    && x.destination == mv.destination
+                    && x.current_coords != mv.current_coords
+            })
+            .map(|x| x.current_coords)
+            .collect();
+
+        let origin = mv.current_coords.to_string();
+
+        if rivals.is_empty() {
+            String::new()
+        } else if rivals.iter().all(|x| x.file() != mv.current_coords.file()) {
+            origin[..1].to_string()
+        } else if rivals.iter().all(|x| x.rank() != mv.current_coords.rank()) {
+            origin[1..].to_string()
+        } else {
+            origin
+        }
+    }
+
+    // Reads a Move Written in SAN, Tolerating Missing or Extra Check Marks and Annotations
+    pub fn parse_san(&self, san: &str) -> Result<Move, SanError> {
+        let invalid = || SanError::Invalid(san.to_string());
+
+        let text = san.trim().trim_end_matches(['+', '#', '!', '?']);
+
+        let legal_moves = self.legal_moves();
+
+        // Castling
+        if matches!(text, "O-O" | "0-0" | "O-O-O" | "0-0-0") {
+            let kingside = text.len() == 3;
+
+            return legal_moves
+                .into_iter()
+                .find(|mv| {
+                    mv.kind == MoveKind::Castling && (mv.destination.file() == 7) == kingside
+                })
+                .ok_or_else(|| SanError::Illegal(san.to_string()));
+        }
+
+        let mut chars: Vec<char> = text.chars().collect();
+
+        // Promotion, Written Either "e8=Q" or "e8Q"
+        let promotion = match chars.last().copied().and_then(piece_from_letter) {
+            Some(piece_type) => {
+                chars.pop();
+
+                if chars.last() == Some(&'=') {
+                    chars.pop();
+                }
+
+                Some(piece_type)
+            }
+            None => None,
+        };
+
+        let piece_type = match chars.first().copied().and_then(piece_from_letter) {
+            Some(piece_type) => {
+                chars.remove(0);
+                piece_type
+            }
+            None => PieceType::Pawn,
+        };
+
+        if chars.len() < 2 {
+            return Err(invalid());
+        }
+
+        let destination: Square = chars
+            .split_off(chars.len() - 2)
+            .iter()
+            .collect::<String>()
+            .parse()
+            .map_err(|_| invalid())?;
+
+        if chars.last() == Some(&'x') {
+            chars.pop();
+        }
+
+        // Whatever Is Left Must Be Disambiguation: a File, a Rank or a Whole Square
+        let mut file = None;
+        let mut rank = None;
+
+        for c in chars {
+            match c {
+                'a'..='h' if file.is_none() && rank.is_none() => {
+                    file = Some(c as i32 - 'a' as i32 + 1)
+                }
+                '1'..='8' if rank.is_none() => rank = Some(c as i32 - '0' as i32),
+                _ => return Err(invalid()),
+            }
+        }
+
+        let mut candidates = legal_moves.into_iter().filter(|mv| {
+            mv.piece_type == piece_type
+                && mv.destination == destination
+                && mv.promotion == promotion
+                && file.is_none_or(|x| mv.current_coords.file() == x)
+                && rank.is_none_or(|x| mv.current_coords.rank() == x)
+        });
+
+        match (candidates.next(), candidates.next()) {
+            (Some(mv), None) => Ok(mv),
+            (Some(_), Some(_)) => Err(SanError::Ambiguous(san.to_string())),
+            _ => Err(SanError::Illegal(san.to_string())),
+        }
+    }
+}
@@ -0,0 +1,363 @@
+use super::eval::piece_value;
+use super::moves::Move;
+use super::piece::PieceType;
+use super::state::State;
+use super::tt::{score_from_table, score_to_table, Bound, TranspositionTable, DEFAULT_SIZE_MB};
+use std::time::{Duration, Instant};
+
+pub const MATE: i32 = 30000;
+const INFINITY: i32 = 32000;
+const MAX_DEPTH: u32 = 64;
+
+// A Capture That Cannot Lift the Score Near Alpha Even With This Much to Spare Is Not Searched
+const DELTA_MARGIN: i32 = 200;
+
+// Scores Beyond This Are Forced Mates, Counted in Plies From the Root
+pub const MATE_BOUND: i32 = MATE - MAX_DEPTH as i32;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SearchLimits {
+    pub depth: u32,
+    pub time: Duration,
+    pub nodes: Option<u64>,
+}
+
+impl Default for SearchLimits {
+    fn default() -> SearchLimits {
+        SearchLimits {
+            depth: MAX_DEPTH,
+            time: Duration::from_secs(1),
+            nodes: None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SearchResult {
+    pub best_move: Option<Move>,
+    pub score: i32,
+    pub depth: u32,
+    pub nodes: u64,
+}
+
+struct Search<'a> {
+    limits: SearchLimits,
+    table: &'a mut TranspositionTable,
+    started: Instant,
+    nodes: u64,
+    can_stop: bool,
+    stopped: bool,
+}
+
+impl Search<'_> {
+    // Only Checks the Clock Every Few Thousand Nodes
+    fn out_of_time(&mut self) -> bool {
+        if self.can_stop
+            && (self.nodes.is_multiple_of(2048) && self.started.elapsed() >= self.limits.time
+                || self.limits.nodes.is_some_and(|x| self.nodes >= x))
+        {
+            self.stopped = true;
+        }
+
+        self.stopped
+    }
+
+    fn negamax(
+        &mut self,
+        state: &mut State,
+        depth: u32,
+        ply: u32,
+        mut alpha: i32,
+        beta: i32,
+    ) -> i32 {
+        self.nodes += 1;
+
+        if self.out_of_time() {
+            return 0;
+        }
+
+        if is_draw(state) {
+            return 0;
+        }
+
+        if depth == 0 {
+            return self.quiesce(state, ply, alpha, beta);
+        }
+
+        // A Deep Enough Stored Result Settles the Node, and Any Stored Move Is Tried First
+        let entry = self.table.probe(state.hash);
+
+        if let Some(entry) = entry.filter(|x| x.depth >= depth) {
+            let score = score_from_table(entry.score, ply);
+
+            match entry.bound {
+                Bound::Exact => return score,
+                Bound::Lower if score >= beta => return beta,
+                Bound::Upper if score <= alpha => return alpha,
+                _ => {}
+            }
+        }
+
+        let mut moves = state.legal_moves();
+
+        if moves.is_empty() {
+            return if state.is_in_check(state.first_player_turn) {
+                -(MATE - ply as i32)
+            } else {
+                0
+            };
+        }
+
+        order_moves(&mut moves, entry.and_then(|x| x.best_move));
+
+        let mut best_move = None;
+        let mut bound = Bound::Upper;
+
+        for mv in moves {
+            state.make_move(mv);
+            let score = -self.negamax(state, depth - 1, ply + 1, -beta, -alpha);
+            state.unmake_move();
+
+            if self.stopped {
+                return 0;
+            }
+
+            if score >= beta {
+                self.table.store(
+                    state.hash,
+                    Some(mv),
+                    score_to_table(beta, ply),
+                    depth,
+                    Bound::Lower,
+                );
+
+                return beta;
+            }
+
+            if score > alpha {
+                alpha = score;
+                best_move = Some(mv);
+                bound = Bound::Exact;
+            }
+        }
+
+        self.table.store(
+            state.hash,
+            best_move,
+            score_to_table(alpha, ply),
+            depth,
+            bound,
+        );
+
+        alpha
+    }
+
+    // Plays Out Captures and Promotions Until the Position Is Quiet, Answering Every Check
+    fn quiesce(&mut self, state: &mut State, ply: u32, mut alpha: i32, beta: i32) -> i32 {
+        self.nodes += 1;
+
+        if self.out_of_time() {
+            return 0;
+        }
+
+        // Mate Scores Only Count Distances Up to MAX_DEPTH, so Endless Checks Stop Here
+        if ply >= MAX_DEPTH {
+            return state.relative_score();
+        }
+
+        let in_check = state.is_in_check(state.first_player_turn);
+        let mut moves = state.legal_moves();
+
+        if in_check && moves.is_empty() {
+            return -(MATE - ply as i32);
+        }
+
+        // Stand Pat: Outside of Check the Side to Move Can Decline Every Capture
+        let stand_pat = state.relative_score();
+
+        if !in_check {
+            if stand_pat >= beta {
+                return beta;
+            }
+
+            alpha = alpha.max(stand_pat);
+
+            moves.retain(|mv| {
+                let gain = mv.captured.map_or(0, piece_value)
+                    + mv.promotion
+                        .map_or(0, |x| piece_value(x) - piece_value(PieceType::Pawn));
+
+                (mv.is_capture() || mv.promotion.is_some())
+                    && stand_pat + gain + DELTA_MARGIN > alpha
+                    && state.see(*mv) >= 0
+            });
+        }
+
+        order_moves(&mut moves, None);
+
+        for mv in moves {
+            state.make_move(mv);
+            let score = -self.quiesce(state, ply + 1, -beta, -alpha);
+            state.unmake_move();
+
+            if self.stopped {
+                return 0;
+            }
+
+            if score >= beta {
+                return beta;
+            }
+
+            alpha = alpha.max(score);
+        }
+
+        alpha
+    }
+}
+
+fn is_draw(state: &State) -> bool {
+    state.halfmove_clock >= 100 || state.repetition_count() >= 2 || state.is_insufficient_material()
+}
+
+// Best Move From the Last Iteration First, Then Captures by Most Valuable Victim
+fn order_moves(moves: &mut [Move], best: Option<Move>) {
+    moves.sort_by_key(|&mv| {
+        let mut score = 0;
+
+        if Some(mv) == best {
+            score += 100_000;
+        }
+
+        if let Some(captured) = mv.captured {
+            score += 10_000 + 10 * piece_value(captured) - piece_value(mv.piece_type);
+        }
+
+        if let Some(promotion) = mv.promotion {
+            score += 9_000 + piece_value(promotion);
+        }
+
+        -score
+    });
+}
+
+impl State {
+    // Searches With a Fresh Table of the Default Size
+    pub fn search(&self, limits: SearchLimits) -> SearchResult {
+        self.search_with_table(limits, &mut TranspositionTable::new(DEFAULT_SIZE_MB))
+    }
+
+    // Iterative Deepening Negamax, Keeping the Last Fully Searched Depth's Answer
+    pub fn search_with_table(
+        &self,
+        limits: SearchLimits,
+        table: &mut TranspositionTable,
+    ) -> SearchResult {
+        self.iterate(limits, table, false).0
+    }
+
+    // Every Root Move With Its Own Score, Best First, for Players That Should Not Always Pick the Best
+    pub fn rank_moves(
+        &self,
+        limits: SearchLimits,
+        table: &mut TranspositionTable,
+    ) -> Vec<(Move, i32)> {
+        self.iterate(limits, table, true).1
+    }
+
+    // Without a Full Window Only the Best Root Move's Score Is Exact, the Rest Being Upper Bounds
+    fn iterate(
+        &self,
+        limits: SearchLimits,
+        table: &mut TranspositionTable,
+        full_window: bool,
+    ) -> (SearchResult, Vec<(Move, i32)>) {
+        let mut state = self.clone();
+
+        table.new_search();
+
+        let mut search = Search {
+            limits,
+            table,
+            started: Instant::now(),
+            nodes: 0,
+            can_stop: false,
+            stopped: false,
+        };
+
+        let mut result = SearchResult {
+            best_move: None,
+            score: 0,
+            depth: 0,
+            nodes: 0,
+        };
+
+        let mut ranking = Vec::new();
+
+        let mut moves = state.legal_moves();
+
+        // A Table Carried Over From an Earlier Search May Already Know the Best Move
+        let mut hash_move = search.table.probe(state.hash).and_then(|x| x.best_move);
+
+        for depth in 1..=limits.depth.min(MAX_DEPTH) {
+            if moves.is_empty() {
+                break;
+            }
+
+            order_moves(&mut moves, result.best_move.or(hash_move.take()));
+
+            let mut alpha = -INFINITY;
+            let mut best_move = None;
+            let mut scores = Vec::with_capacity(moves.len());
+
+            for &mv in &moves {
+                let window = if full_window { -INFINITY } else { alpha };
+
+                state.make_move(mv);
+                let score = -search.negamax(&mut state, depth - 1, 1, -INFINITY, -window);
+                state.unmake_move();
+
+                if search.stopped {
+                    break;
+                }
+
+                scores.push((mv, score));
+
+                if score > alpha {
+                    alpha = score;
+                    best_move = Some(mv);
+                }
+            }
+
+            if search.stopped {
+                break;
+            }
+
+            search
+                .table
+                .store(state.hash, best_move, alpha, depth, Bound::Exact);
+
+            result = SearchResult {
+                best_move,
+                score: alpha,
+                depth,
+                nodes: search.nodes,
+            };
+
+            scores.sort_by_key(|&(_, score)| -score);
+            ranking = scores;
+
+            // The First Iteration Always Completes so There Is Always a Move to Play
+            search.can_stop = true;
+
+            let spent = search.started.elapsed() >= limits.time
+                || limits.nodes.is_some_and(|x| search.nodes >= x);
+
+            if alpha.abs() >= MATE_BOUND || spent {
+                break;
+            }
+        }
+
+        result.nodes = search.nodes;
+        (result, ranking)
+    }
+}
@@ -0,0 +1,131 @@
+use super::bitboard::{bit, lsb, squares, Bitboard};
+use super::eval::piece_value;
+use super::moves::{Move, MoveKind};
+use super::piece::PieceType;
+use super::square::Square;
+use super::state::State;
+
+// Cheapest First, so Each Side Recaptures With Its Least Valuable Attacker
+const ATTACKER_ORDER: [PieceType; 6] = [
+    PieceType::Pawn,
+    PieceType::Knight,
+    PieceType::Bishop,
+    PieceType::Rook,
+    PieceType::Queen,
+    PieceType::King,
+];
+
+impl State {
+    // Material Won or Lost by the Mover Once Every Capture on the Destination Has Been Played Out
+    pub fn see(&self, mv: Move) -> i32 {
+        let board = &self.board;
+        let target = mv.destination;
+
+        let first_player = match board.piece_at(mv.current_coords) {
+            Some((_, first_player)) => first_player,
+            None => return 0,
+        };
+
+        let mut occupied = board.occupied & !bit(mv.current_coords);
+
+        if mv.kind == MoveKind::EnPassant {
+            occupied &= !bit(mv.capture_coords());
+        }
+
+        let mut gains = Vec::with_capacity(32);
+        gains.push(mv.captured.map_or(0, piece_value));
+
+        // The Piece Left Standing on the Target, Which the Next Capture Wins
+        let mut on_target = match mv.promotion {
+            Some(promotion) => {
+                gains[0] += piece_value(promotion) - piece_value(PieceType::Pawn);
+                promotion
+            }
+            None => mv.piece_type,
+        };
+
+        let mut side = !first_player;
+
+        loop {
+            let attackers = self.attackers_to(target, side, occupied) & occupied;
+
+            let attacker = ATTACKER_ORDER.iter().find_map(|&piece_type| {
+                let candidates = attackers & board.pieces_of(piece_type, side);
+                (candidates != 0).then(|| (piece_type, lsb(candidates)))
+            });
+
+            let (piece_type, from) = match attacker {
+                Some(attacker) => attacker,
+                None => break,
+            };
+
+            // A King May Only Recapture When Nothing Can Take It Back
+            if piece_type == PieceType::King
+                && self.attackers_to(target, !side, occupied) & occupied != 0
+            {
+                break;
+            }
+
+            gains.push(piece_value(on_target) - gains[gains.len() - 1]);
+
+            on_target = piece_type;
+            occupied &= !bit(from);
+            side = !side;
+        }
+
+        // Either Side Can Stop Capturing Whenever Carrying On Would Lose More
+        while gains.len() > 1 {
+            let last = gains.pop().unwrap();
+            let previous = gains.len() - 1;
+
+            gains[previous] = -(-gains[previous]).max(last);
+        }
+
+        gains[0]
+    }
+
+    // Pieces of the Given Side the Opponent Could Win Material by Capturing
+    pub fn hanging_pieces(&self, first_player: bool) -> Vec<Square> {
+        let board = &self.board;
+        let occupied = board.occupied;
+
+        let mut hanging: Bitboard = 0;
+
+        for square in squares(board.colour(first_player)) {
+            let (captured, _) = board.piece_at(square).unwrap();
+
+            if captured == PieceType::King {
+                continue;
+            }
+
+            let attackers = self.attackers_to(square, !first_player, occupied);
+
+            for from in squares(attackers) {
+                let (piece_type, _) = board.piece_at(from).unwrap();
+
+                // The King Cannot Take a Defended Piece
+                if piece_type == PieceType::King
+                    && self.attackers_to(square, first_player, occupied & !bit(from)) != 0
+                {
+                    continue;
+                }
+
+                let mv = Move {
+                    current_coords: from,
+                    destination: square,
+                    piece_type,
+                    captured: Some(captured),
+                    promotion: None,
+                    kind: MoveKind::Normal,
+                };
+
+                if self.see(mv) > 0 {
+                    hanging |= bit(square);
+                    break;
+                }
+            }
+        }
+
+        squares(hanging).collect()
+    }
+}
@@ -1,91 +1,228 @@
-use super::board::Space;
+use super::bitboard::{
+    between, bishop_attacks, bit, king_attacks, knight_attacks, lsb, pawn_attacks, rook_attacks,
+    squares, Bitboard,
+};
+use super::board::Board;
+use super::difficulty::Difficulty;
+use super::moves::{Move, MoveError, MoveKind, Undo};
+use super::outcome::GameOutcome;
 use super::piece::{Piece, PieceType};
 use super::player::Player;
-use once_cell::sync::Lazy;
-use std::collections::HashMap;
+use super::square::Square;
+use super::tt::DEFAULT_SIZE_MB;
+use super::zobrist::{castling_key, en_passant_key, piece_key, KEYS};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CastlingRights {
+    pub white_kingside: bool,
+    pub white_queenside: bool,
+    pub black_kingside: bool,
+    pub black_queenside: bool,
+}
+
+impl CastlingRights {
+    pub fn get(&self, first_player: bool) -> (bool, bool) {
+        if first_player {
+            (self.white_kingside, self.white_queenside)
+        } else {
+            (self.black_kingside, self.black_queenside)
+        }
+    }
+
+    // Revoke Rights When a King or Rook Square Is Vacated or Captured On
+    fn revoke(&mut self, square: Square) {
+        match square {
+            Square::E1 => {
+                self.white_kingside = false;
+                self.white_queenside = false;
+            }
+            Square::E8 => {
+                self.black_kingside = false;
+                self.black_queenside = false;
+            }
+            Square::H1 => self.white_kingside = false,
+            Square::A1 => self.white_queenside = false,
+            Square::H8 => self.black_kingside = false,
+            Square::A8 => self.black_queenside = false,
+            _ => {}
+        }
+    }
+}
 
 #[derive(Clone)]
 pub struct State {
-    white: Player,
-    black: Player,
-    pub board: HashMap<(i32, i32), Space>,
+    pub white: Player,
+    pub black: Player,
+    pub board: Board,
+    pub castling: CastlingRights,
+    pub en_passant: Option<Square>,
+    pub first_player_turn: bool,
+    pub halfmove_clock: u32,
+    pub fullmove_number: u32,
+    pub history: Vec<Undo>,
+    pub outcome: Option<GameOutcome>,
+    // The Side Whose Draw Offer Is Waiting on an Answer
+    pub draw_offer: Option<bool>,
+    pub hash: u64,
+    pub position_history: Vec<u64>,
 }
 
 impl State {
-    fn setup_players(&mut self, first_player: bool, ai: bool) {
-        let mut player = Player {
-            first_player: true,
-            pieces: Vec::new(),
-            ai: false,
-            king_coord: (0, 0),
+    pub fn new(ai: bool) -> State {
+        State::with_table_size(ai, DEFAULT_SIZE_MB)
+    }
+
+    // A New Game Whose Computer Player, if Any, Gets a Table of the Given Size
+    pub fn with_table_size(ai: bool, table_mb: usize) -> State {
+        let mut state = State::empty();
+
+        state.castling = CastlingRights {
+            white_kingside: true,
+            white_queenside: true,
+            black_kingside: true,
+            black_queenside: true,
         };
 
-        player.ai = ai;
+        state.setup_players(true, false, table_mb);
+        state.setup_players(false, ai, table_mb);
 
-        let mut starting_pos = if first_player { 1 } else { 8 };
+        state.reset_hash();
 
-        let mut pieces: Vec<Piece> = vec![
-            Piece {
-                current_coords: (1, starting_pos),
-                piece_type: PieceType::Rook,
-            },
-            Piece {
-                current_coords: (8, starting_pos),
-                piece_type: PieceType::Rook,
-            },
-            Piece {
-                current_coords: (2, starting_pos),
-                piece_type: PieceType::Knight,
-            },
-            Piece {
-                current_coords: (7, starting_pos),
-                piece_type: PieceType::Knight,
-            },
-            Piece {
-                current_coords: (3, starting_pos),
-                piece_type: PieceType::Bishop,
-            },
-            Piece {
-                current_coords: (6, starting_pos),
-                piece_type: PieceType::Bishop,
+        state
+    }
+
+    pub fn empty() -> State {
+        let mut state = State {
+            white: Player {
+                first_player: true,
+                ai: false,
+                difficulty: Difficulty::default(),
+                table: None,
             },
-            Piece {
-                current_coords: (4, starting_pos),
-                piece_type: PieceType::Queen,
+            black: Player {
+                first_player: false,
+                ai: false,
+                difficulty: Difficulty::default(),
+                table: None,
             },
-            Piece {
-                current_coords: (5, starting_pos),
-                piece_type: PieceType::King,
+            board: Board::empty(),
+            castling: CastlingRights {
+                white_kingside: false,
+                white_queenside: false,
+                black_kingside: false,
+                black_queenside: false,
             },
-        ];
+            en_passant: None,
+            first_player_turn: true,
+            halfmove_clock: 0,
+            fullmove_number: 1,
+            history: Vec::new(),
+            outcome: None,
+            draw_offer: None,
+            hash: 0,
+            position_history: Vec::new(),
+        };
 
-        player.king_coord = (5, starting_pos);
+        state.reset_hash();
 
-        if first_player {
-            starting_pos += 1;
+        state
+    }
+
+    pub fn place_piece(&mut self, piece_type: PieceType, square: Square, first_player: bool) {
+        self.board.put(square, piece_type, first_player);
+        self.hash ^= piece_key(piece_type, square, first_player);
+    }
+
+    pub fn compute_hash(&self) -> u64 {
+        let mut hash = castling_key(self.castling) ^ self.en_passant_hash();
+
+        for square in Square::all() {
+            if let Some((piece_type, first_player)) = self.board.piece_at(square) {
+                hash ^= piece_key(piece_type, square, first_player);
+            }
+        }
+
+        if !self.first_player_turn {
+            hash ^= KEYS.side;
+        }
+
+        hash
+    }
+
+    // Start a Fresh Position History After Setting Up a Position
+    pub fn reset_hash(&mut self) {
+        self.hash = self.compute_hash();
+        self.position_history = vec![self.hash];
+    }
+
+    // Only Hash the En Passant File When the Capture Is Actually Available
+    fn en_passant_hash(&self) -> u64 {
+        let target = match self.en_passant {
+            Some(square) => square,
+            None => return 0,
+        };
+
+        let first_player = self.first_player_turn;
+        let pawns = self.board.pieces_of(PieceType::Pawn, first_player);
+
+        if pawn_attacks(target, !first_player) & pawns != 0 {
+            en_passant_key(target.file())
         } else {
-            starting_pos -= 1;
+            0
         }
+    }
+
+    pub fn repetition_count(&self) -> usize {
+        self.position_history
+            .iter()
+            .rev()
+            .take(self.halfmove_clock as usize + 1)
+            .step_by(2)
+            .filter(|&&x| x == self.hash)
+            .count()
+    }
+
+    fn setup_players(&mut self, first_player: bool, ai: bool, table_mb: usize) {
+        let mut player = Player {
+            first_player,
+            ai,
+            difficulty: Difficulty::default(),
+            table: None,
+        };
 
-        for n in 1..=8 {
-            pieces.push(Piece {
-                current_coords: (n, starting_pos),
-                piece_type: PieceType::Pawn,
-            });
+        if ai {
+            player.set_table_size(table_mb);
         }
 
-        for piece in &pieces {
-            self.board.insert(
-                piece.current_coords,
-                Space {
-                    occupied: Some(piece.clone()),
-                    evaluated_value: 0,
-                },
+        let back_rank_pos = if first_player { 1 } else { 8 };
+        let pawn_pos = if first_player { 2 } else { 7 };
+
+        let back_rank = [
+            PieceType::Rook,
+            PieceType::Knight,
+            PieceType::Bishop,
+            PieceType::Queen,
+            PieceType::King,
+            PieceType::Bishop,
+            PieceType::Knight,
+            PieceType::Rook,
+        ];
+
+        for (n, piece_type) in back_rank.into_iter().enumerate() {
+            let file = n as i32 + 1;
+
+            self.board.put(
+                Square::new(file, back_rank_pos).unwrap(),
+                piece_type,
+                first_player,
+            );
+            self.board.put(
+                Square::new(file, pawn_pos).unwrap(),
+                PieceType::Pawn,
+                first_player,
             );
         }
 
-        player.pieces = pieces;
-
         if first_player {
             self.white = player;
         } else {
@@ -93,174 +230,405 @@ impl State {
         }
     }
 
-    fn setup_spaces(&mut self) {
-        for i in 3..=6 {
-            for j in 1..=8 {
-                self.board.insert(
-                    (i, j),
-                    Space {
-                        occupied: None,
-                        evaluated_value: 0,
-                    },
-                );
-            }
+    pub fn piece_at(&self, square: Square) -> Option<Piece> {
+        self.board.piece_at(square).map(|(piece_type, _)| Piece {
+            piece_type,
+            current_coords: square,
+        })
+    }
+
+    pub fn pieces(&self, first_player: bool) -> Vec<Piece> {
+        squares(self.board.colour(first_player))
+            .filter_map(|x| self.piece_at(x))
+            .collect()
+    }
+
+    pub fn is_empty(&self, squares: &[Square]) -> bool {
+        squares
+            .iter()
+            .all(|&square| self.board.occupied & bit(square) == 0)
+    }
+
+    pub fn king_square(&self, first_player: bool) -> Square {
+        lsb(self.board.pieces_of(PieceType::King, first_player))
+    }
+
+    // Pieces of the Given Side Attacking `square`, With Sliders Seeing Through to `occupied`
+    pub fn attackers_to(&self, square: Square, first_player: bool, occupied: Bitboard) -> Bitboard {
+        let board = &self.board;
+
+        let queens = board.pieces_of(PieceType::Queen, first_player);
+        let rooks = board.pieces_of(PieceType::Rook, first_player) | queens;
+        let bishops = board.pieces_of(PieceType::Bishop, first_player) | queens;
+
+        (pawn_attacks(square, !first_player) & board.pieces_of(PieceType::Pawn, first_player))
+            | (knight_attacks(square) & board.pieces_of(PieceType::Knight, first_player))
+            | (king_attacks(square) & board.pieces_of(PieceType::King, first_player))
+            | (rook_attacks(square, occupied) & rooks)
+            | (bishop_attacks(square, occupied) & bishops)
+    }
+
+    pub fn is_in_check(&self, first_player: bool) -> bool {
+        self.attackers_to(
+            self.king_square(first_player),
+            !first_player,
+            self.board.occupied,
+        ) != 0
+    }
+
+    // Enemy Sliders Lined Up With the King Behind Exactly One of Our Pieces
+    pub fn pinners(&self, first_player: bool) -> Vec<(Square, Square)> {
+        let board = &self.board;
+        let king_square = self.king_square(first_player);
+
+        let queens = board.pieces_of(PieceType::Queen, !first_player);
+        let snipers = (rook_attacks(king_square, 0)
+            & (board.pieces_of(PieceType::Rook, !first_player) | queens))
+            | (bishop_attacks(king_square, 0)
+                & (board.pieces_of(PieceType::Bishop, !first_player) | queens));
+
+        squares(snipers)
+            .filter_map(|sniper| {
+                let blockers = between(king_square, sniper) & board.occupied;
+
+                if blockers.count_ones() == 1 && blockers & board.colour(first_player) != 0 {
+                    Some((lsb(blockers), sniper))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    pub fn capture_piece(&mut self, first_player: bool, square: Square) -> Piece {
+        let (piece_type, _) = self.board.remove(square).unwrap();
+
+        self.hash ^= piece_key(piece_type, square, !first_player);
+
+        Piece {
+            piece_type,
+            current_coords: square,
         }
     }
 
-    pub fn get_coords(&self, first_player: bool) -> (Vec<(i32, i32)>, Vec<(i32, i32)>) {
-        if first_player {
-            return (
-                self.white.pieces.iter().map(|x| x.current_coords).collect(),
-                self.black.pieces.iter().map(|x| x.current_coords).collect(),
-            );
-        } else {
-            return (
-                self.black.pieces.iter().map(|x| x.current_coords).collect(),
-                self.white.pieces.iter().map(|x| x.current_coords).collect(),
+    fn restore_piece(&mut self, first_player: bool, piece: Piece) {
+        self.place_piece(piece.piece_type, piece.current_coords, !first_player);
+    }
+
+    pub fn make_move(&mut self, mv: Move) -> Undo {
+        let first_player = self.first_player_turn;
+
+        let mut undo = Undo {
+            mv,
+            first_player,
+            captured: None,
+            castling: self.castling,
+            en_passant: self.en_passant,
+            halfmove_clock: self.halfmove_clock,
+            fullmove_number: self.fullmove_number,
+            hash: self.hash,
+            outcome: self.outcome,
+        };
+
+        self.hash ^= castling_key(self.castling) ^ self.en_passant_hash();
+
+        self.castling.revoke(mv.current_coords);
+        self.castling.revoke(mv.destination);
+
+        self.en_passant = None;
+
+        if mv.is_capture() {
+            undo.captured = Some(self.capture_piece(first_player, mv.capture_coords()));
+        }
+
+        self.relocate_piece(mv.current_coords, mv.destination, first_player);
+
+        // Record En Passant Target
+        if mv.kind == MoveKind::DoublePawnPush {
+            self.en_passant = Square::new(
+                mv.destination.file(),
+                (mv.destination.rank() + mv.current_coords.rank()) / 2,
             );
         }
-    }
 
-    pub fn get_potential_check(&self, first_player: bool) -> ((i32, i32), Vec<Piece>) {
-        if first_player {
-            (self.white.king_coord, self.black.pieces.clone())
+        // Move Castling Rook
+        if let Some((rook_coords, rook_destination)) = mv.castling_rook() {
+            self.relocate_piece(rook_coords, rook_destination, first_player);
+        }
+
+        if let Some(piece_type) = mv.promotion {
+            self.promote_piece(mv.destination, piece_type, first_player);
+        }
+
+        // Update Clocks
+        if mv.piece_type == PieceType::Pawn || mv.is_capture() {
+            self.halfmove_clock = 0;
         } else {
-            (self.black.king_coord, self.white.pieces.clone())
+            self.halfmove_clock += 1;
         }
-    }
 
-    pub fn capture_piece(&mut self, first_player: bool, coord: (i32, i32)) {
-        let a = self.board.get_mut(&coord);
-        a.unwrap().occupied = None;
+        if !first_player {
+            self.fullmove_number += 1;
+        }
 
-        let (_, enemy_coords) = self.get_coords(first_player);
+        self.first_player_turn = !first_player;
 
-        let index = enemy_coords.iter().position(|&x| x == coord);
-        let index = index.unwrap();
+        self.hash ^= castling_key(self.castling) ^ self.en_passant_hash() ^ KEYS.side;
 
-        let pieces = if first_player {
-            &mut self.white.pieces
-        } else {
-            &mut self.black.pieces
-        };
+        self.history.push(undo.clone());
+        self.position_history.push(self.hash);
 
-        pieces.remove(index);
+        undo
     }
 
-    pub fn update_move(
-        &mut self,
-        current_coords: (i32, i32),
-        destination: (i32, i32),
-        first_player: bool,
-    ) {
-        // Remove Space Allocation
-        let a = self.board.get_mut(&current_coords).unwrap();
-        a.occupied = None;
+    pub fn unmake_move(&mut self) -> Option<Move> {
+        let undo = self.history.pop()?;
+        let mv = undo.mv;
 
-        let (player_coords, _) = self.get_coords(first_player);
+        if mv.promotion.is_some() {
+            self.promote_piece(mv.destination, PieceType::Pawn, undo.first_player);
+        }
 
-        let index = player_coords.iter().position(|&x| x == current_coords);
-        let index = index.unwrap();
+        if let Some((rook_coords, rook_destination)) = mv.castling_rook() {
+            self.relocate_piece(rook_destination, rook_coords, undo.first_player);
+        }
 
-        let pieces = if first_player {
-            &mut self.white.pieces
-        } else {
-            &mut self.black.pieces
-        };
+        self.relocate_piece(mv.destination, mv.current_coords, undo.first_player);
 
-        if let Some(piece) = pieces.get_mut(index) {
-            let b = self.board.get_mut(&destination).unwrap();
-            piece.current_coords = destination;
-            b.occupied = Some(piece.clone());
+        if let Some(piece) = undo.captured {
+            self.restore_piece(undo.first_player, piece);
+        }
 
-            // Update King Position
-            if piece.piece_type == PieceType::King {
-                if first_player {
-                    self.white.king_coord = destination;
-                } else {
-                    self.black.king_coord = destination;
-                }
+        self.castling = undo.castling;
+        self.en_passant = undo.en_passant;
+        self.first_player_turn = undo.first_player;
+
+        // A Result Declared After the Move Outlives It, Only One the Move Brought About Is Undone
+        if !self.outcome.is_some_and(|x| x.is_declared()) {
+            self.outcome = undo.outcome;
+        }
+
+        self.halfmove_clock = undo.halfmove_clock;
+        self.fullmove_number = undo.fullmove_number;
+        self.hash = undo.hash;
+
+        self.position_history.pop();
+
+        Some(mv)
+    }
+
+    fn relocate_piece(&mut self, current_coords: Square, destination: Square, first_player: bool) {
+        if let Some((piece_type, _)) = self.board.remove(current_coords) {
+            self.board.put(destination, piece_type, first_player);
+
+            self.hash ^= piece_key(piece_type, current_coords, first_player)
+                ^ piece_key(piece_type, destination, first_player);
+        }
+    }
+
+    // Resolve Client Input Into a Legal Move, Defaulting Promotions to a Queen
+    pub fn find_move(
+        &self,
+        current_coords: Square,
+        destination: Square,
+        promotion: Option<PieceType>,
+    ) -> Result<Move, MoveError> {
+        match self.board.piece_at(current_coords) {
+            Some((_, first_player)) if first_player != self.first_player_turn => {
+                return Err(MoveError::OutOfTurn)
             }
+            None => return Err(MoveError::NoPiece(current_coords)),
+            _ => {}
+        }
+
+        let candidates: Vec<Move> = self
+            .legal_moves()
+            .into_iter()
+            .filter(|mv| mv.current_coords == current_coords && mv.destination == destination)
+            .collect();
+
+        if candidates.is_empty() {
+            return Err(MoveError::Illegal(current_coords, destination));
         }
+
+        candidates
+            .into_iter()
+            .find(|mv| {
+                mv.promotion.is_none()
+                    || mv.promotion == Some(promotion.unwrap_or(PieceType::Queen))
+            })
+            .ok_or(MoveError::InvalidPromotion)
     }
 
-    fn determine_endgame(&mut self, first_player: bool) -> bool {
-        let (king_coord, enemy_pieces) = self.get_potential_check(first_player);
+    // Resolve a UCI Move Such as "e2e4" or "e7e8q", Which Must Name Its Promotion Exactly
+    pub fn parse_uci(&self, uci: &str) -> Result<Move, MoveError> {
+        if self.outcome.is_some() {
+            return Err(MoveError::GameOver);
+        }
 
-        let player_pieces = if first_player {
-            self.white.pieces.clone()
-        } else {
-            self.black.pieces.clone()
+        if !uci.is_ascii() || !(4..=5).contains(&uci.len()) {
+            return Err(MoveError::Malformed);
+        }
+
+        let current_coords: Square = uci[0..2].parse().map_err(|_| MoveError::Malformed)?;
+        let destination: Square = uci[2..4].parse().map_err(|_| MoveError::Malformed)?;
+
+        let promotion = match &uci[4..] {
+            "" => None,
+            "q" => Some(PieceType::Queen),
+            "r" => Some(PieceType::Rook),
+            "b" => Some(PieceType::Bishop),
+            "n" => Some(PieceType::Knight),
+            _ => return Err(MoveError::InvalidPromotion),
         };
 
-        let mut val = false;
+        let mv = self.find_move(current_coords, destination, promotion)?;
 
-        let checks: Vec<Piece> = enemy_pieces
-            .iter()
-            .cloned()
-            .filter(|x| {
-                let mut piece = x.clone();
-                piece
-                    .generate_possible_moves(!first_player)
-                    .contains(&king_coord)
-            })
-            .collect();
+        if mv.promotion != promotion {
+            return Err(MoveError::InvalidPromotion);
+        }
 
-        if !checks.is_empty() {
-            // CheckMate
-            if checks.len() == 1 {
-                let legal_moves: Vec<(i32, i32)> = player_pieces
-                    .iter()
-                    .flat_map(|x| {
-                        let mut piece = x.clone();
-                        piece.generate_legal_moves(first_player)
-                    })
-                    .collect();
-
-                if legal_moves.is_empty() {
-                    val = true;
-                }
-            } else {
-                let pos = self.board.get(&king_coord).unwrap();
-                let mut king = pos.occupied.clone().unwrap();
+        Ok(mv)
+    }
 
-                let legal_moves = king.generate_legal_moves(first_player);
+    pub fn apply(&mut self, mv: Move) -> Result<Undo, MoveError> {
+        if self.outcome.is_some() {
+            return Err(MoveError::GameOver);
+        }
 
-                if legal_moves.is_empty() {
-                    val = true;
-                }
+        if let Some((_, first_player)) = self.board.piece_at(mv.current_coords) {
+            if first_player != self.first_player_turn {
+                return Err(MoveError::OutOfTurn);
             }
-        } else {
-            // Stalemate
-            let legal_moves: Vec<(i32, i32)> = player_pieces
-                .iter()
-                .flat_map(|x| {
-                    let mut piece = x.clone();
-                    piece.generate_legal_moves(first_player)
-                })
-                .collect();
-
-            if legal_moves.is_empty() {
-                val = true;
+        }
+
+        if !self.legal_moves().contains(&mv) {
+            return Err(MoveError::Illegal(mv.current_coords, mv.destination));
+        }
+
+        let first_player = self.first_player_turn;
+        let undo = self.make_move(mv);
+
+        // Playing On Declines Whatever Offer the Opponent Left Standing
+        if self.draw_offer == Some(!first_player) {
+            self.draw_offer = None;
+        }
+
+        self.outcome = self.determine_endgame();
+
+        Ok(undo)
+    }
+
+    pub fn promote_piece(&mut self, square: Square, piece_type: PieceType, first_player: bool) {
+        if let Some((current_type, _)) = self.board.remove(square) {
+            self.board.put(square, piece_type, first_player);
+
+            self.hash ^= piece_key(current_type, square, first_player)
+                ^ piece_key(piece_type, square, first_player);
+        }
+    }
+
+    pub fn determine_endgame(&self) -> Option<GameOutcome> {
+        if self.outcome.is_some() {
+            return self.outcome;
+        }
+
+        let first_player = self.first_player_turn;
+
+        if self.legal_moves().is_empty() {
+            if self.is_in_check(first_player) {
+                return Some(GameOutcome::Checkmate {
+                    first_player_wins: !first_player,
+                });
             }
+
+            return Some(GameOutcome::Stalemate);
         }
 
-        val
+        if self.is_insufficient_material() {
+            return Some(GameOutcome::InsufficientMaterial);
+        }
+
+        if self.repetition_count() >= 5 {
+            return Some(GameOutcome::FivefoldRepetition);
+        }
+
+        if self.halfmove_clock >= 150 {
+            return Some(GameOutcome::SeventyFiveMoveRule);
+        }
+
+        None
+    }
+
+    // Draws a Player May Claim but Which Do Not End the Game on Their Own
+    pub fn claim_draw(&mut self) -> Result<GameOutcome, MoveError> {
+        if self.repetition_count() >= 3 {
+            return self.conclude(GameOutcome::ThreefoldRepetition);
+        }
+
+        if self.halfmove_clock >= 100 {
+            return self.conclude(GameOutcome::FiftyMoveRule);
+        }
+
+        Err(MoveError::NoDrawToClaim)
+    }
+
+    pub fn resign(&mut self, first_player: bool) -> Result<GameOutcome, MoveError> {
+        self.conclude(GameOutcome::Resignation {
+            first_player_wins: !first_player,
+        })
     }
-}
 
-pub static mut STATE: Lazy<State> = Lazy::new(|| State {
-    white: Player {
-        first_player: true,
-        pieces: Vec::new(),
-        ai: false,
-        king_coord: (5, 1),
-    },
-    black: Player {
-        first_player: false,
-        pieces: Vec::new(),
-        ai: false,
-        king_coord: (5, 8),
-    },
-    board: HashMap::new(),
-});
+    pub fn timeout(&mut self, first_player: bool) -> Result<GameOutcome, MoveError> {
+        if !self.can_checkmate(!first_player) {
+            return self.conclude(GameOutcome::TimeoutVsInsufficientMaterial);
+        }
+
+        self.conclude(GameOutcome::Timeout {
+            first_player_wins: !first_player,
+        })
+    }
+
+    // An Offer Stands Until the Other Side Accepts, Declines or Plays On
+    pub fn offer_draw(&mut self, first_player: bool) -> Result<(), MoveError> {
+        if self.outcome.is_some() {
+            return Err(MoveError::GameOver);
+        }
+
+        self.draw_offer = Some(first_player);
+
+        Ok(())
+    }
+
+    pub fn accept_draw(&mut self, first_player: bool) -> Result<GameOutcome, MoveError> {
+        if self.draw_offer != Some(!first_player) {
+            return Err(MoveError::NoDrawOffer);
+        }
+
+        let outcome = self.conclude(GameOutcome::Agreement)?;
+
+        self.draw_offer = None;
+
+        Ok(outcome)
+    }
+
+    pub fn decline_draw(&mut self, first_player: bool) -> Result<(), MoveError> {
+        if self.draw_offer != Some(!first_player) {
+            return Err(MoveError::NoDrawOffer);
+        }
+
+        self.draw_offer = None;
+
+        Ok(())
+    }
+
+    fn conclude(&mut self, outcome: GameOutcome) -> Result<GameOutcome, MoveError> {
+        if self.outcome.is_some() {
+            return Err(MoveError::GameOver);
+        }
+
+        self.outcome = Some(outcome);
+
+        Ok(outcome)
+    }
+}
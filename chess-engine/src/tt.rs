@@ -0,0 +1,122 @@
+use super::moves::Move;
+use super::search::MATE_BOUND;
+use std::mem::size_of;
+
+pub const DEFAULT_SIZE_MB: usize = 16;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Bound {
+    // The Score Is the Position's True Value at This Depth
+    Exact,
+    // The Search Failed High, so the True Value Is at Least the Score
+    Lower,
+    // No Move Reached Alpha, so the True Value Is at Most the Score
+    Upper,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Entry {
+    pub key: u64,
+    pub best_move: Option<Move>,
+    pub score: i32,
+    pub depth: u32,
+    pub bound: Bound,
+    generation: u8,
+}
+
+pub struct TranspositionTable {
+    entries: Vec<Option<Entry>>,
+    generation: u8,
+}
+
+impl TranspositionTable {
+    pub fn new(size_mb: usize) -> TranspositionTable {
+        let count = (size_mb * 1024 * 1024 / size_of::<Option<Entry>>()).max(1);
+
+        TranspositionTable {
+            entries: vec![None; count],
+            generation: 0,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.iter_mut().for_each(|x| *x = None);
+        self.generation = 0;
+    }
+
+    // Marks Everything Stored So Far as Belonging to an Earlier Search
+    pub fn new_search(&mut self) {
+        self.generation = self.generation.wrapping_add(1);
+    }
+
+    fn index(&self, key: u64) -> usize {
+        (key % self.entries.len() as u64) as usize
+    }
+
+    pub fn probe(&self, key: u64) -> Option<Entry> {
+        self.entries[self.index(key)].filter(|x| x.key == key)
+    }
+
+    // Keeps the Deeper Result Unless the Slot Is Stale or Already Holds This Position
+    pub fn store(
+        &mut self,
+        key: u64,
+        best_move: Option<Move>,
+        score: i32,
+        depth: u32,
+        bound: Bound,
+    ) {
+        let index = self.index(key);
+        let generation = self.generation;
+
+        if let Some(existing) = self.entries[index] {
+            let replace =
+                existing.key == key || existing.generation != generation || depth >= existing.depth;
+
+            if !replace {
+                return;
+            }
+        }
+
+        // Hold On to the Old Move When a Fail-Low Had None to Offer
+        let best_move = best_move.or_else(|| {
+            self.entries[index]
+                .filter(|x| x.key == key)
+                .and_then(|x| x.best_move)
+        });
+
+        self.entries[index] = Some(Entry {
+            key,
+            best_move,
+            score,
+            depth,
+            bound,
+            generation,
+        });
+    }
+}
+
+// Mate Scores Are Stored Relative to the Node Rather Than the Root, so They Stay True Wherever the Position Recurs
+pub fn score_to_table(score: i32, ply: u32) -> i32 {
+    if score >= MATE_BOUND {
+        score + ply as i32
+    } else if score <= -MATE_BOUND {
+        score - ply as i32
+    } else {
+        score
+    }
+}
+
+pub fn score_from_table(score: i32, ply: u32) -> i32 {
+    if score >= MATE_BOUND {
+        score - ply as i32
+    } else if score <= -MATE_BOUND {
+        score + ply as i32
+    } else {
+        score
+    }
+}
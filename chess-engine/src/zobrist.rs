@@ -0,0 +1,71 @@
+use super::piece::PieceType;
+use super::square::Square;
+use super::state::CastlingRights;
+use once_cell::sync::Lazy;
+
+pub struct ZobristKeys {
+    pub pieces: [[[u64; 64]; 6]; 2],
+    pub side: u64,
+    pub castling: [u64; 16],
+    pub en_passant: [u64; 8],
+}
+
+// Fixed Seed so Hashes Are Stable Across Runs
+pub static KEYS: Lazy<ZobristKeys> = Lazy::new(|| {
+    let mut seed: u64 = 0x9E37_79B9_7F4A_7C15;
+
+    let mut next = || {
+        // SplitMix64
+        seed = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = seed;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    };
+
+    let mut keys = ZobristKeys {
+        pieces: [[[0; 64]; 6]; 2],
+        side: 0,
+        castling: [0; 16],
+        en_passant: [0; 8],
+    };
+
+    for color in keys.pieces.iter_mut() {
+        for piece in color.iter_mut() {
+            for square in piece.iter_mut() {
+                *square = next();
+            }
+        }
+    }
+
+    keys.side = next();
+
+    for key in keys.castling.iter_mut() {
+        *key = next();
+    }
+
+    for key in keys.en_passant.iter_mut() {
+        *key = next();
+    }
+
+    keys
+});
+
+pub fn piece_key(piece_type: PieceType, square: Square, first_player: bool) -> u64 {
+    let color = if first_player { 0 } else { 1 };
+
+    KEYS.pieces[color][piece_type as usize - 1][square.index()]
+}
+
+pub fn castling_key(castling: CastlingRights) -> u64 {
+    let index = castling.white_kingside as usize
+        | (castling.white_queenside as usize) << 1
+        | (castling.black_kingside as usize) << 2
+        | (castling.black_queenside as usize) << 3;
+
+    KEYS.castling[index]
+}
+
+pub fn en_passant_key(file: i32) -> u64 {
+    KEYS.en_passant[(file - 1) as usize]
+}
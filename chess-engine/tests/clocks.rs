@@ -0,0 +1,61 @@
+mod common;
+
+use chess_engine::moves::MoveError;
+use chess_engine::outcome::GameOutcome;
+use chess_engine::state::State;
+use common::play;
+
+#[test]
+fn halfmove_clock_resets_on_pawn_moves_and_captures() {
+    let mut state = State::new(false);
+
+    play(&mut state, &["g1f3", "b8c6"]);
+    assert_eq!(state.halfmove_clock, 2);
+
+    play(&mut state, &["e2e4"]);
+    assert_eq!(state.halfmove_clock, 0);
+
+    play(&mut state, &["c6d4", "f3d4"]);
+    assert_eq!(state.halfmove_clock, 0);
+
+    play(&mut state, &["g8f6"]);
+    assert_eq!(
+        state.to_fen(),
+        "r1bqkb1r/pppppppp/5n2/8/3NP3/8/PPPP1PPP/RNBQKB1R w KQkq - 1 4"
+    );
+}
+
+#[test]
+fn fifty_move_rule_is_claimable() {
+    let mut state = State::from_fen("4k3/8/8/8/8/8/8/R3K3 w - - 99 80").unwrap();
+
+    assert_eq!(state.claim_draw().err(), Some(MoveError::NoDrawToClaim));
+
+    play(&mut state, &["a1a2"]);
+
+    assert_eq!(state.outcome, None);
+    assert_eq!(state.claim_draw(), Ok(GameOutcome::FiftyMoveRule));
+}
+
+#[test]
+fn seventy_five_move_rule_is_automatic() {
+    let mut state = State::from_fen("4k3/8/8/8/8/8/8/R3K3 w - - 149 100").unwrap();
+
+    play(&mut state, &["a1a2"]);
+
+    assert_eq!(state.outcome, Some(GameOutcome::SeventyFiveMoveRule));
+}
+
+#[test]
+fn checkmate_takes_precedence_over_seventy_five_moves() {
+    let mut state = State::from_fen("6k1/5ppp/8/8/8/8/8/R3K3 w - - 149 100").unwrap();
+
+    play(&mut state, &["a1a8"]);
+
+    assert_eq!(
+        state.outcome,
+        Some(GameOutcome::Checkmate {
+            first_player_wins: true
+        })
+    );
+}
@@ -0,0 +1,26 @@
+use chess_engine::search::SearchLimits;
+use chess_engine::square::Square;
+use chess_engine::state::State;
+use std::time::Duration;
+
+// Applies Moves Written as Coordinate Pairs, e.g. "e2e4"
+#[allow(dead_code)]
+pub fn play(state: &mut State, moves: &[&str]) {
+    for mv in moves {
+        let current_coords: Square = mv[0..2].parse().unwrap();
+        let destination: Square = mv[2..4].parse().unwrap();
+
+        let mv = state.find_move(current_coords, destination, None).unwrap();
+        state.apply(mv).unwrap();
+    }
+}
+
+// Depth-Only Limits, With Time to Spare Even in Debug Builds
+#[allow(dead_code)]
+pub fn limits(depth: u32) -> SearchLimits {
+    SearchLimits {
+        depth,
+        time: Duration::from_secs(60),
+        nodes: None,
+    }
+}
@@ -0,0 +1,129 @@
+mod common;
+
+use chess_engine::difficulty::{Difficulty, Strength};
+use chess_engine::search::{SearchLimits, MATE};
+use chess_engine::square::Square;
+use chess_engine::state::State;
+use chess_engine::tt::TranspositionTable;
+use common::{limits, play};
+
+#[test]
+fn levels_are_named() {
+    for level in Difficulty::LEVELS {
+        assert_eq!(Difficulty::from_name(level.name()), Some(level));
+    }
+
+    assert_eq!(Difficulty::from_name("Casual"), Some(Difficulty::Casual));
+    assert_eq!(Difficulty::from_name("grandmaster"), None);
+    assert_eq!(Difficulty::default(), Difficulty::Expert);
+    assert_eq!(Difficulty::Elo(1500).to_string(), "elo 1500");
+}
+
+#[test]
+fn elo_targets_sit_between_levels() {
+    assert_eq!(Difficulty::Elo(100).elo(), 600);
+    assert_eq!(Difficulty::Elo(3000).elo(), 2200);
+
+    assert_eq!(
+        Difficulty::Elo(1400).strength(),
+        Difficulty::Intermediate.strength()
+    );
+
+    let casual = Difficulty::Casual.strength();
+    let intermediate = Difficulty::Intermediate.strength();
+    let between = Difficulty::Elo(1200).strength();
+
+    assert_eq!(between.depth, casual.depth);
+    assert!(between.noise < casual.noise && between.noise > intermediate.noise);
+    assert!(between.blunder < casual.blunder && between.blunder > intermediate.blunder);
+
+    // Stronger Ratings Never Make More Mistakes
+    let mut previous = Difficulty::Elo(600).strength();
+
+    for elo in (700..=2200).step_by(100) {
+        let strength = Difficulty::Elo(elo).strength();
+
+        assert!(strength.depth >= previous.depth);
+        assert!(strength.noise <= previous.noise);
+        assert!(strength.blunder <= previous.blunder);
+
+        previous = strength;
+    }
+
+    assert!(Difficulty::Elo(2200).strength().is_full_strength());
+}
+
+#[test]
+fn node_limits_cut_the_search_short() {
+    let state = State::new(false);
+
+    let result = state.search(SearchLimits {
+        nodes: Some(2_000),
+        ..limits(20)
+    });
+
+    assert!(result.best_move.is_some());
+    assert!(result.depth < 20);
+}
+
+#[test]
+fn ranked_moves_cover_every_legal_move() {
+    let state = State::from_fen("4k3/8/8/3r4/8/8/3R4/4K3 w - - 0 1").unwrap();
+
+    let ranked = state.rank_moves(limits(2), &mut TranspositionTable::new(1));
+
+    assert_eq!(ranked.len(), state.legal_moves().len());
+    assert!(ranked.windows(2).all(|x| x[0].1 >= x[1].1));
+
+    let (best, _) = ranked[0];
+
+    assert_eq!(best.destination, Square::D5);
+}
+
+#[test]
+fn mistakes_stay_within_the_margin_and_avoid_mate() {
+    let state = State::from_fen("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1").unwrap();
+    let moves = state.legal_moves();
+
+    let ranked = vec![(moves[0], 300), (moves[1], 250), (moves[2], -MATE + 2)];
+
+    let always_best = Strength {
+        depth: 1,
+        nodes: None,
+        noise: 0,
+        blunder: 0,
+        margin: 0,
+    };
+
+    assert_eq!(always_best.pick(&ranked), Some(moves[0]));
+
+    let always_wrong = Strength {
+        blunder: 100,
+        margin: 100_000,
+        ..always_best
+    };
+
+    for _ in 0..20 {
+        assert_eq!(always_wrong.pick(&ranked), Some(moves[1]));
+    }
+
+    assert_eq!(always_best.pick(&[]), None);
+}
+
+#[test]
+fn every_level_plays_legal_moves() {
+    for difficulty in Difficulty::LEVELS {
+        if difficulty == Difficulty::Expert {
+            continue;
+        }
+
+        let mut state = State::new(true);
+        state.black.difficulty = difficulty;
+
+        play(&mut state, &["e2e4"]);
+
+        let mv = state.black.choose_move(&state).unwrap();
+
+        assert!(state.legal_moves().contains(&mv));
+    }
+}
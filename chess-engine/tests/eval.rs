@@ -0,0 +1,80 @@
+use chess_engine::piece::PieceType;
+use chess_engine::square::Square;
+use chess_engine::state::State;
+
+#[test]
+fn start_position_is_level() {
+    let evaluation = State::new(false).evaluate();
+
+    assert_eq!(evaluation.material, 0);
+    assert_eq!(evaluation.placement, 0);
+    assert_eq!(evaluation.mobility, 0);
+    assert_eq!(evaluation.king_safety, 0);
+    assert_eq!(evaluation.pawn_structure, 0);
+    assert_eq!(evaluation.total(), 0);
+}
+
+#[test]
+fn square_values_add_up_to_the_total() {
+    let state =
+        State::from_fen("r1bqk2r/pp2bppp/2n2n2/3p4/3P4/2NB1N2/PP3PPP/R1BQ1RK1 b kq - 3 9").unwrap();
+    let evaluation = state.evaluate();
+
+    let sum: i32 = evaluation.spaces.iter().map(|x| x.evaluated_value).sum();
+
+    assert_eq!(evaluation.spaces.len(), 64);
+    assert_eq!(sum, evaluation.total());
+
+    let space = evaluation.space(Square::F3);
+    let piece = space.occupied.as_ref().unwrap();
+
+    assert_eq!(piece.piece_type, PieceType::Knight);
+    assert!(space.evaluated_value > 0);
+    assert!(evaluation.space(Square::E4).occupied.is_none());
+    assert_eq!(evaluation.space(Square::E4).evaluated_value, 0);
+}
+
+#[test]
+fn mirrored_positions_score_opposite() {
+    let white = State::from_fen("4k3/pp3ppp/8/3N4/8/8/PPP2PPP/R3K3 w Q - 0 1").unwrap();
+    let black = State::from_fen("r3k3/ppp2ppp/8/8/3n4/8/PP3PPP/4K3 b q - 0 1").unwrap();
+
+    assert_eq!(white.evaluate().total(), -black.evaluate().total());
+    assert_eq!(white.relative_score(), black.relative_score());
+}
+
+#[test]
+fn relative_score_follows_the_side_to_move() {
+    let white = State::from_fen("4k3/8/8/8/8/8/8/3QK3 w - - 0 1").unwrap();
+    let black = State::from_fen("4k3/8/8/8/8/8/8/3QK3 b - - 0 1").unwrap();
+
+    assert!(white.evaluate().material >= 900);
+    assert_eq!(white.relative_score(), white.evaluate().total());
+    assert_eq!(black.relative_score(), -black.evaluate().total());
+}
+
+#[test]
+fn pawn_structure_terms() {
+    // A Passed Pawn on the Sixth Is Worth More Than One Held Back by an Enemy Pawn
+    let passed = State::from_fen("4k3/8/3P4/8/8/8/8/4K3 w - - 0 1").unwrap();
+    let blocked = State::from_fen("4k3/2p5/3P4/8/8/8/8/4K3 w - - 0 1").unwrap();
+
+    assert!(passed.evaluate().pawn_structure > 0);
+    assert!(blocked.evaluate().pawn_structure < passed.evaluate().pawn_structure);
+
+    // Doubled and Isolated Pawns Are Penalised
+    let healthy = State::from_fen("4k3/5ppp/8/8/8/8/5PPP/4K3 w - - 0 1").unwrap();
+    let damaged = State::from_fen("4k3/5ppp/8/8/8/5P2/5P1P/4K3 w - - 0 1").unwrap();
+
+    assert_eq!(healthy.evaluate().pawn_structure, 0);
+    assert!(damaged.evaluate().pawn_structure < 0);
+}
+
+#[test]
+fn exposed_king_is_penalised() {
+    let sheltered =
+        State::from_fen("rnbqk2r/pppp1ppp/8/8/8/8/PPPPPPPP/RNBQ1RK1 w kq - 0 1").unwrap();
+    let exposed = State::from_fen("rnbqk2r/pppp1ppp/8/8/8/8/PPPPP3/RNBQ1RK1 w kq - 0 1").unwrap();
+
+    assert!(exposed.evaluate().king_safety < sheltered.evaluate().king_safety);
+}
@@ -0,0 +1,91 @@
+use chess_engine::fen::{FenError, START_FEN};
+use chess_engine::square::Square;
+use chess_engine::state::State;
+
+#[test]
+fn start_position_round_trip() {
+    assert_eq!(State::new(false).to_fen(), START_FEN);
+    assert_eq!(State::from_fen(START_FEN).unwrap().to_fen(), START_FEN);
+}
+
+#[test]
+fn round_trip_preserves_every_field() {
+    let fens = [
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3",
+        "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 b - - 12 40",
+    ];
+
+    for fen in fens {
+        assert_eq!(State::from_fen(fen).unwrap().to_fen(), fen);
+    }
+}
+
+#[test]
+fn export_tracks_moves() {
+    let mut state = State::new(false);
+
+    let mv = state.find_move(Square::E2, Square::E4, None).unwrap();
+    state.make_move(mv);
+
+    assert_eq!(
+        state.to_fen(),
+        "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1"
+    );
+}
+
+#[test]
+fn descriptive_errors() {
+    assert_eq!(
+        State::from_fen("8/8/8/8/8/8/8/8 w - -").err(),
+        Some(FenError::KingCount(true, 0))
+    );
+    assert_eq!(
+        State::from_fen("rnbqkbnr/pppppppp/9/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1").err(),
+        Some(FenError::InvalidPiece('9'))
+    );
+    assert_eq!(
+        State::from_fen("rnbqkbnr/pppppppp/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1").err(),
+        Some(FenError::RankCount(7))
+    );
+    assert_eq!(
+        State::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR x KQkq - 0 1").err(),
+        Some(FenError::SideToMove("x".to_string()))
+    );
+    assert_eq!(
+        State::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq e4 0 1").err(),
+        Some(FenError::EnPassant("e4".to_string()))
+    );
+    assert_eq!(
+        State::from_fen("P3k3/8/8/8/8/8/8/4K3 w - - 0 1").err(),
+        Some(FenError::PawnOnBackRank(Square::A8))
+    );
+    assert_eq!(
+        State::from_fen("4k3/8/8/8/8/8/8/4K2p b - - 0 1").err(),
+        Some(FenError::PawnOnBackRank(Square::H1))
+    );
+    assert_eq!(
+        State::from_fen("4k3/8/8/3P4/8/8/8/4K3 w - e6 0 1").err(),
+        Some(FenError::NoEnPassantPawn(Square::E6))
+    );
+    assert_eq!(
+        State::from_fen("4k3/4n3/8/3Pp3/8/8/8/4K3 w - e6 0 1").err(),
+        Some(FenError::NoEnPassantPawn(Square::E6))
+    );
+    assert_eq!(
+        State::from_fen("4k3/8/4n3/3Pp3/8/8/8/4K3 w - e6 0 1").err(),
+        Some(FenError::NoEnPassantPawn(Square::E6))
+    );
+    assert_eq!(
+        State::from_fen("4k3/8/8/8/3pP3/8/8/4K3 b - e3 0 1").map(|x| x.en_passant),
+        Ok(Some(Square::E3))
+    );
+    assert_eq!(
+        State::from_fen("4k3/8/8/8/8/8/4R3/4K3 w - - 0 1").err(),
+        Some(FenError::OpponentInCheck)
+    );
+    assert_eq!(
+        State::from_fen(START_FEN.trim_end_matches(" 0 1")).map(|x| x.to_fen()),
+        Ok(START_FEN.to_string())
+    );
+}
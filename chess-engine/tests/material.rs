@@ -0,0 +1,58 @@
+mod common;
+
+use chess_engine::outcome::GameOutcome;
+use chess_engine::state::State;
+use common::play;
+
+fn insufficient(fen: &str) -> bool {
+    State::from_fen(fen).unwrap().is_insufficient_material()
+}
+
+#[test]
+fn detects_insufficient_material() {
+    assert!(insufficient("4k3/8/8/8/8/8/8/4K3 w - - 0 1"));
+    assert!(insufficient("4k3/8/8/8/8/8/8/2B1K3 w - - 0 1"));
+    assert!(insufficient("4k3/8/8/8/8/8/8/1N2K3 w - - 0 1"));
+    assert!(insufficient("2b1k3/8/8/8/8/8/8/3BK3 w - - 0 1"));
+    assert!(insufficient("4kb2/8/8/8/8/8/8/B3K3 w - - 0 1"));
+
+    assert!(!insufficient("1b2k3/8/8/8/8/8/8/3BK3 w - - 0 1"));
+    assert!(!insufficient("4k3/8/8/8/8/8/8/1NN1K3 w - - 0 1"));
+    assert!(!insufficient("1n2k3/8/8/8/8/8/8/1N2K3 w - - 0 1"));
+    assert!(!insufficient("4k3/8/8/8/8/8/4P3/4K3 w - - 0 1"));
+}
+
+#[test]
+fn capture_into_bare_kings_draws() {
+    let mut state = State::from_fen("4k3/8/8/8/8/8/3q4/4K3 w - - 0 1").unwrap();
+
+    play(&mut state, &["e1d2"]);
+
+    assert_eq!(state.outcome, Some(GameOutcome::InsufficientMaterial));
+}
+
+#[test]
+fn timeout_against_a_lone_king_is_a_draw() {
+    let mut state = State::from_fen("4k3/8/8/8/8/8/4P3/4K3 b - - 0 1").unwrap();
+
+    assert_eq!(
+        state.clone().timeout(false),
+        Ok(GameOutcome::Timeout {
+            first_player_wins: true
+        })
+    );
+    assert_eq!(
+        state.timeout(true),
+        Ok(GameOutcome::TimeoutVsInsufficientMaterial)
+    );
+}
+
+#[test]
+fn dead_positions() {
+    let knight = State::from_fen("4k3/8/8/8/8/8/8/1N2K3 w - - 0 1").unwrap();
+    let knight_v_pawn = State::from_fen("4k3/4p3/8/8/8/8/8/1N2K3 w - - 0 1").unwrap();
+
+    assert!(knight.is_dead_position());
+    assert!(!knight_v_pawn.is_dead_position());
+    assert!(knight_v_pawn.can_checkmate(true));
+}
@@ -0,0 +1,128 @@
+mod common;
+
+use chess_engine::difficulty::Difficulty;
+use chess_engine::moves::MoveError;
+use chess_engine::outcome::GameOutcome;
+use chess_engine::square::Square;
+use chess_engine::state::State;
+use common::play;
+
+#[test]
+fn checkmate_ends_the_game() {
+    let mut state = State::new(false);
+
+    play(&mut state, &["f2f3", "e7e5", "g2g4", "d8h4"]);
+
+    assert_eq!(
+        state.outcome,
+        Some(GameOutcome::Checkmate {
+            first_player_wins: false
+        })
+    );
+    assert_eq!(state.outcome.unwrap().result(), "0-1");
+    assert_eq!(state.resign(true).err(), Some(MoveError::GameOver));
+}
+
+#[test]
+fn stalemate() {
+    let state = State::from_fen("7k/5Q2/6K1/8/8/8/8/8 b - - 0 1").unwrap();
+
+    assert_eq!(state.determine_endgame(), Some(GameOutcome::Stalemate));
+}
+
+#[test]
+fn resignation_blocks_further_moves() {
+    let mut state = State::new(false);
+
+    assert_eq!(
+        state.resign(true),
+        Ok(GameOutcome::Resignation {
+            first_player_wins: false
+        })
+    );
+
+    let mv = state.find_move(Square::E2, Square::E4, None).unwrap();
+
+    assert_eq!(state.apply(mv).err(), Some(MoveError::GameOver));
+}
+
+#[test]
+fn undoing_a_move_keeps_a_declared_result() {
+    let mut state = State::new(false);
+
+    play(&mut state, &["e2e4"]);
+    state.resign(false).unwrap();
+
+    assert!(state.unmake_move().is_some());
+    assert_eq!(
+        state.outcome,
+        Some(GameOutcome::Resignation {
+            first_player_wins: true
+        })
+    );
+}
+
+#[test]
+fn undoing_the_mating_move_reopens_the_game() {
+    let mut state = State::new(false);
+
+    play(&mut state, &["f2f3", "e7e5", "g2g4", "d8h4"]);
+
+    assert!(state.outcome.is_some());
+    assert!(state.unmake_move().is_some());
+    assert_eq!(state.outcome, None);
+}
+
+#[test]
+fn an_unanswered_draw_offer_does_not_end_the_game() {
+    let mut state = State::new(false);
+
+    state.offer_draw(true).unwrap();
+
+    assert_eq!(state.outcome, None);
+    assert_eq!(state.accept_draw(true).err(), Some(MoveError::NoDrawOffer));
+
+    // Black Plays On Instead of Answering, Which Lets the Offer Lapse
+    play(&mut state, &["e2e4", "e7e5"]);
+
+    assert_eq!(state.outcome, None);
+    assert_eq!(state.accept_draw(false).err(), Some(MoveError::NoDrawOffer));
+}
+
+#[test]
+fn an_accepted_draw_offer_ends_the_game() {
+    let mut state = State::new(false);
+
+    state.offer_draw(true).unwrap();
+    play(&mut state, &["e2e4"]);
+
+    assert_eq!(state.accept_draw(false), Ok(GameOutcome::Agreement));
+    assert_eq!(state.draw_offer, None);
+}
+
+#[test]
+fn a_declined_draw_offer_is_withdrawn() {
+    let mut state = State::new(false);
+
+    state.offer_draw(false).unwrap();
+
+    assert_eq!(state.decline_draw(true), Ok(()));
+    assert_eq!(state.accept_draw(true).err(), Some(MoveError::NoDrawOffer));
+    assert_eq!(state.outcome, None);
+}
+
+#[test]
+fn the_computer_only_takes_a_draw_it_does_not_stand_better_in() {
+    // Black Is the Computer; a Queen Up It Plays On, a Queen Down It Takes the Draw
+    let mut ahead = State::from_fen("3qk3/8/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+    let mut behind = State::from_fen("4k3/8/8/8/8/8/8/3QK3 w - - 0 1").unwrap();
+
+    for state in [&mut ahead, &mut behind] {
+        state.black.ai = true;
+        state.black.difficulty = Difficulty::Beginner;
+    }
+
+    assert!(!ahead.black.accepts_draw(&ahead));
+    assert!(behind.black.accepts_draw(&behind));
+    assert!(!behind.white.accepts_draw(&behind));
+}
@@ -0,0 +1,67 @@
+use chess_engine::fen::START_FEN;
+use chess_engine::state::State;
+
+fn assert_perft(state: &mut State, expected: &[u64]) {
+    for (depth, &nodes) in expected.iter().enumerate() {
+        assert_eq!(state.perft(depth as u32 + 1), nodes, "depth {}", depth + 1);
+    }
+}
+
+#[test]
+fn start_position() {
+    let mut state = State::from_fen(START_FEN).unwrap();
+
+    assert_perft(&mut state, &[20, 400, 8902, 197281]);
+}
+
+#[test]
+fn kiwipete() {
+    let mut state =
+        State::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1")
+            .unwrap();
+
+    assert_perft(&mut state, &[48, 2039, 97862]);
+}
+
+#[test]
+fn position_3() {
+    let mut state = State::from_fen("8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1").unwrap();
+
+    assert_perft(&mut state, &[14, 191, 2812, 43238]);
+}
+
+#[test]
+fn position_4() {
+    let mut state =
+        State::from_fen("r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1")
+            .unwrap();
+
+    assert_perft(&mut state, &[6, 264, 9467]);
+}
+
+#[test]
+fn position_5() {
+    let mut state =
+        State::from_fen("rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 0 1").unwrap();
+
+    assert_perft(&mut state, &[44, 1486, 62379]);
+}
+
+#[test]
+fn position_6() {
+    let mut state =
+        State::from_fen("r4rk1/1pp1qppp/p1np1n2/2b1p1B1/2B1P1b1/P1NP1N2/1PP1QPPP/R4RK1 w - - 0 1")
+            .unwrap();
+
+    assert_perft(&mut state, &[46, 2079, 89890]);
+}
+
+#[test]
+fn divide_sums_to_perft() {
+    let mut state = State::new(false);
+
+    let divide = state.divide(3);
+
+    assert_eq!(divide.len(), 20);
+    assert_eq!(divide.iter().map(|(_, nodes)| nodes).sum::<u64>(), 8902);
+}
@@ -0,0 +1,216 @@
+mod common;
+
+use chess_engine::fen::FenError;
+use chess_engine::pgn::{date_tag, parse_pgn, Pgn, PgnError};
+use chess_engine::state::State;
+use common::play;
+use std::time::{Duration, UNIX_EPOCH};
+
+#[test]
+fn writes_the_seven_tag_roster_and_result() {
+    let mut state = State::new(false);
+
+    play(&mut state, &["f2f3", "e7e5", "g2g4", "d8h4"]);
+
+    assert_eq!(
+        state.to_pgn(),
+        "[Event \"?\"]\n\
+         [Site \"?\"]\n\
+         [Date \"????.??.??\"]\n\
+         [Round \"?\"]\n\
+         [White \"?\"]\n\
+         [Black \"?\"]\n\
+         [Result \"0-1\"]\n\
+         \n\
+         1. f3 e5 2. g4 Qh4# 0-1\n"
+    );
+}
+
+#[test]
+fn writes_custom_tags_comments_and_clocks() {
+    let mut state = State::new(false);
+
+    play(&mut state, &["e2e4", "e7e5", "g1f3"]);
+
+    let mut pgn = Pgn::new();
+    pgn.set_tag("White", "Anderssen, \"The\" Master");
+    pgn.set_tag("TimeControl", "300+2");
+    pgn.set_tag("GameId", "1234");
+    pgn.comment(0, "best by test");
+    pgn.clock(0, Duration::from_secs(298));
+    pgn.clock(2, Duration::from_secs(3725));
+
+    let text = pgn.write(&state);
+
+    assert!(text.contains("[White \"Anderssen, \\\"The\\\" Master\"]\n"));
+    assert!(text.ends_with(
+        "[Result \"*\"]\n\
+         [TimeControl \"300+2\"]\n\
+         [GameId \"1234\"]\n\
+         \n\
+         1. e4 {best by test [%clk 0:04:58]} 1... e5 2. Nf3 {[%clk 1:02:05]} *\n"
+    ));
+}
+
+#[test]
+fn records_non_standard_starting_positions() {
+    let mut state = State::from_fen("4k3/8/8/8/8/8/4P3/4K3 b - - 0 7").unwrap();
+
+    play(&mut state, &["e8d7", "e2e4"]);
+
+    let text = state.to_pgn();
+
+    assert!(text.contains("[SetUp \"1\"]\n[FEN \"4k3/8/8/8/8/8/4P3/4K3 b - - 0 7\"]\n"));
+    assert!(text.ends_with("\n7... Kd7 8. e4 *\n"));
+}
+
+#[test]
+fn wraps_long_movetext() {
+    let mut state = State::new(false);
+
+    for _ in 0..4 {
+        play(&mut state, &["g1f3", "g8f6", "f3g1", "f6g8"]);
+    }
+
+    let text = state.to_pgn();
+    let movetext: Vec<&str> = text.split("\n\n").nth(1).unwrap().lines().collect();
+
+    assert!(movetext.len() > 1);
+    assert!(movetext.iter().all(|line| line.len() < 80));
+}
+
+#[test]
+fn formats_dates() {
+    assert_eq!(date_tag(UNIX_EPOCH), "1970.01.01");
+    assert_eq!(
+        date_tag(UNIX_EPOCH + Duration::from_secs(951_782_400)),
+        "2000.02.29"
+    );
+}
+
+const ARCHIVE: &str = r#"% exported by hand
+[Event "Casual Game"]
+[White "Anderssen, \"Adolf\""]
+[Black "Kieseritzky"]
+[Result "1-0"]
+
+1. e4 e5 2. f4 $1 exf4 {The King's Gambit,
+accepted} 3. Bc4 Qh4+ (3... d5 4. Bxd5 (4. exd5) Nf6) 4. Kf1 ; forfeits castling
+b5?! 5.Bxb5 Nf6 1-0
+
+[Event "Second"]
+[SetUp "1"]
+[FEN "4k3/8/8/8/8/8/4P3/4K3 w - - 0 1"]
+
+1. e4 Kd7 2. e5 *
+"#;
+
+#[test]
+fn parses_multi_game_files() {
+    let games = parse_pgn(ARCHIVE).unwrap();
+
+    assert_eq!(games.len(), 2);
+
+    assert_eq!(games[0].tag("White"), Some("Anderssen, \"Adolf\""));
+    assert_eq!(games[0].result.as_deref(), Some("1-0"));
+
+    let sans: Vec<&str> = games[0].moves.iter().map(|x| x.san.as_str()).collect();
+    assert_eq!(
+        sans,
+        ["e4", "e5", "f4", "exf4", "Bc4", "Qh4+", "Kf1", "b5?!", "Bxb5", "Nf6"]
+    );
+
+    assert_eq!(games[1].tag("Event"), Some("Second"));
+    assert_eq!(games[1].result.as_deref(), Some("*"));
+}
+
+#[test]
+fn replays_games_into_state() {
+    let games = parse_pgn(ARCHIVE).unwrap();
+
+    let state = games[0].replay().unwrap();
+    assert_eq!(
+        state.to_fen(),
+        "rnb1kb1r/p1pp1ppp/5n2/1B6/4Pp1q/8/PPPP2PP/RNBQ1KNR w kq - 1 6"
+    );
+
+    let state = games[1].replay().unwrap();
+    assert_eq!(state.to_fen(), "8/3k4/8/4P3/8/8/8/4K3 b - - 0 2");
+}
+
+#[test]
+fn reads_back_what_it_writes() {
+    let mut state = State::new(false);
+
+    play(
+        &mut state,
+        &["e2e4", "c7c5", "g1f3", "d7d6", "f1b5", "c8d7"],
+    );
+
+    let mut pgn = Pgn::new();
+    pgn.comment(1, "the Sicilian");
+
+    let games = parse_pgn(&pgn.write(&state)).unwrap();
+
+    assert_eq!(games.len(), 1);
+    assert_eq!(games[0].replay().unwrap().to_fen(), state.to_fen());
+}
+
+#[test]
+fn reports_the_first_illegal_move() {
+    let text = "[Event \"?\"]\n\n1. e4 e5 2. Nf3 Nc6\n3. Bb5 Ke6 4. O-O *\n";
+    let games = parse_pgn(text).unwrap();
+
+    assert_eq!(
+        games[0].replay().err(),
+        Some(PgnError::IllegalMove {
+            line: 4,
+            column: 8,
+            mv: "3... Ke6".to_string(),
+            reason: "Ke6 is not a legal move".to_string(),
+        })
+    );
+}
+
+#[test]
+fn reports_syntax_errors() {
+    assert_eq!(
+        parse_pgn("1. e4 {unfinished\ne5 *").err(),
+        Some(PgnError::Syntax {
+            line: 1,
+            column: 7,
+            message: "unterminated comment",
+        })
+    );
+    assert_eq!(
+        parse_pgn("[Event \"?]\n1. e4 *").err(),
+        Some(PgnError::Syntax {
+            line: 1,
+            column: 1,
+            message: "malformed tag pair",
+        })
+    );
+    assert_eq!(
+        parse_pgn("1. e4 (1. d4 *").err(),
+        Some(PgnError::Syntax {
+            line: 1,
+            column: 15,
+            message: "unterminated variation",
+        })
+    );
+}
+
+#[test]
+fn impossible_setup_positions_are_reported() {
+    let text = "[SetUp \"1\"]\n[FEN \"4k3/8/8/8/8/8/4R3/4K3 w - - 0 1\"]\n\n1. Rxe8 *\n\n\
+                [Event \"Next\"]\n\n1. e4 *\n";
+
+    let games = parse_pgn(text).unwrap();
+
+    assert_eq!(games.len(), 2);
+    assert_eq!(
+        games[0].replay().err(),
+        Some(PgnError::Fen(FenError::OpponentInCheck))
+    );
+    assert!(games[1].replay().is_ok());
+}
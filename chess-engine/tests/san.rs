@@ -0,0 +1,110 @@
+mod common;
+
+use chess_engine::fen::START_FEN;
+use chess_engine::piece::PieceType;
+use chess_engine::san::SanError;
+use chess_engine::square::Square;
+use chess_engine::state::State;
+use common::play;
+
+fn san(fen: &str, from: Square, to: Square) -> String {
+    let state = State::from_fen(fen).unwrap();
+    let mv = state.find_move(from, to, None).unwrap();
+
+    state.move_to_san(mv)
+}
+
+#[test]
+fn writes_pawn_and_piece_moves() {
+    assert_eq!(san(START_FEN, Square::E2, Square::E4), "e4");
+    assert_eq!(san(START_FEN, Square::G1, Square::F3), "Nf3");
+
+    let mut state = State::new(false);
+    play(&mut state, &["e2e4", "d7d5"]);
+
+    let mv = state.find_move(Square::E4, Square::D5, None).unwrap();
+    assert_eq!(state.move_to_san(mv), "exd5");
+}
+
+#[test]
+fn disambiguates_by_file_then_rank_then_square() {
+    let knights = "4k3/8/8/8/8/8/8/1N2KN2 w - - 0 1";
+    assert_eq!(san(knights, Square::B1, Square::D2), "Nbd2");
+    assert_eq!(san(knights, Square::F1, Square::D2), "Nfd2");
+
+    let rooks = "4k3/8/8/R7/8/8/8/R3K3 w - - 0 1";
+    assert_eq!(san(rooks, Square::A1, Square::A3), "R1a3");
+
+    let queens = "8/7K/8/7k/8/Q7/8/Q1Q5 w - - 0 1";
+    assert_eq!(san(queens, Square::A1, Square::B2), "Qa1b2");
+}
+
+#[test]
+fn writes_castling_promotion_check_and_mate() {
+    let castling = "r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1";
+    assert_eq!(san(castling, Square::E1, Square::G1), "O-O");
+    assert_eq!(san(castling, Square::E1, Square::C1), "O-O-O");
+
+    let state = State::from_fen("7k/P7/8/8/8/8/8/K7 w - - 0 1").unwrap();
+    let moves = state.legal_moves();
+    let sans: Vec<String> = moves
+        .iter()
+        .filter(|mv| mv.promotion.is_some())
+        .map(|&mv| state.move_to_san(mv))
+        .collect();
+
+    assert_eq!(sans, ["a8=Q+", "a8=R+", "a8=B", "a8=N"]);
+
+    let mut state = State::new(false);
+    play(&mut state, &["f2f3", "e7e5", "g2g4"]);
+
+    let mv = state.find_move(Square::D8, Square::H4, None).unwrap();
+    assert_eq!(state.move_to_san(mv), "Qh4#");
+}
+
+#[test]
+fn parses_what_it_writes() {
+    let state =
+        State::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1")
+            .unwrap();
+
+    for mv in state.legal_moves() {
+        assert_eq!(state.parse_san(&state.move_to_san(mv)), Ok(mv));
+    }
+}
+
+#[test]
+fn parses_loose_notation() {
+    let state = State::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1").unwrap();
+    let mv = state.parse_san("0-0-0").unwrap();
+    assert_eq!(mv.destination, Square::C1);
+
+    let state = State::from_fen("7k/P7/8/8/8/8/8/K7 w - - 0 1").unwrap();
+    let mv = state.parse_san("a8N").unwrap();
+    assert_eq!(mv.promotion, Some(PieceType::Knight));
+
+    let state = State::new(false);
+    let mv = state.parse_san("Nf3!?").unwrap();
+    assert_eq!(mv.current_coords, Square::G1);
+}
+
+#[test]
+fn rejects_bad_san() {
+    let state = State::new(false);
+
+    assert_eq!(
+        state.parse_san("Nf6"),
+        Err(SanError::Illegal("Nf6".to_string()))
+    );
+    assert_eq!(
+        state.parse_san("xyz"),
+        Err(SanError::Invalid("xyz".to_string()))
+    );
+
+    let state = State::from_fen("4k3/8/8/8/8/8/8/1N2KN2 w - - 0 1").unwrap();
+
+    assert_eq!(
+        state.parse_san("Nd2"),
+        Err(SanError::Ambiguous("Nd2".to_string()))
+    );
+}
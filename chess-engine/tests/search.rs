@@ -0,0 +1,82 @@
+mod common;
+
+use chess_engine::search::MATE_BOUND;
+use chess_engine::square::Square;
+use chess_engine::state::State;
+use common::{limits, play};
+
+#[test]
+fn finds_mate_in_one() {
+    // Back Rank Mate With Ra8
+    let state = State::from_fen("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1").unwrap();
+    let result = state.search(limits(3));
+
+    let mv = result.best_move.unwrap();
+
+    assert_eq!(mv.current_coords, Square::A1);
+    assert_eq!(mv.destination, Square::A8);
+    assert!(result.score >= MATE_BOUND);
+}
+
+#[test]
+fn captures_a_hanging_queen() {
+    let state = State::from_fen("4k3/8/8/3q4/8/8/3R4/4K3 w - - 0 1").unwrap();
+    let mv = state.search(limits(3)).best_move.unwrap();
+
+    assert_eq!(mv.current_coords, Square::D2);
+    assert_eq!(mv.destination, Square::D5);
+}
+
+#[test]
+fn avoids_losing_the_queen() {
+    // The Queen Is Attacked by a Pawn, so Black Must Have Nothing to Take Afterwards
+    let state = State::from_fen("4k3/8/8/8/2p5/3Q4/8/4K3 w - - 0 1").unwrap();
+    let mut after = state.clone();
+
+    let mv = state.search(limits(2)).best_move.unwrap();
+    after.make_move(mv);
+
+    assert!(after
+        .search(limits(1))
+        .best_move
+        .unwrap()
+        .captured
+        .is_none());
+}
+
+#[test]
+fn no_move_without_legal_moves() {
+    // Black Is Stalemated
+    let state = State::from_fen("k7/2Q5/1K6/8/8/8/8/8 b - - 0 1").unwrap();
+    let result = state.search(limits(3));
+
+    assert_eq!(result.best_move, None);
+    assert_eq!(result.score, 0);
+}
+
+#[test]
+fn search_leaves_the_position_untouched() {
+    let state = State::new(false);
+    let fen = state.to_fen();
+
+    let result = state.search(limits(2));
+
+    assert!(result.best_move.is_some());
+    assert_eq!(result.depth, 2);
+    assert_eq!(state.to_fen(), fen);
+}
+
+#[test]
+fn only_computer_players_choose_moves() {
+    let mut state = State::new(true);
+
+    // White Is Human and Black Waits for Its Turn
+    assert_eq!(state.white.choose_move(&state), None);
+    assert_eq!(state.black.choose_move(&state), None);
+
+    play(&mut state, &["e2e4"]);
+
+    let mv = state.black.choose_move(&state).unwrap();
+
+    assert!(state.legal_moves().contains(&mv));
+}
@@ -0,0 +1,96 @@
+mod common;
+
+use chess_engine::search::SearchLimits;
+use chess_engine::square::Square;
+use chess_engine::state::State;
+use common::limits;
+use std::time::Duration;
+
+fn see(fen: &str, uci: &str) -> i32 {
+    let state = State::from_fen(fen).unwrap();
+    let mv = state.parse_uci(uci).unwrap();
+
+    state.see(mv)
+}
+
+#[test]
+fn undefended_capture_wins_the_piece() {
+    assert_eq!(see("4k3/8/8/3r4/8/8/3R4/4K3 w - - 0 1", "d2d5"), 500);
+}
+
+#[test]
+fn defended_capture_is_an_exchange() {
+    // Rook Takes a Pawn Defended by a Pawn
+    assert_eq!(
+        see("4k3/8/4p3/3p4/8/8/3R4/4K3 w - - 0 1", "d2d5"),
+        100 - 500
+    );
+
+    // Pawn Takes a Knight Defended by a Pawn
+    assert_eq!(
+        see("4k3/8/4p3/3n4/4P3/8/8/4K3 w - - 0 1", "e4d5"),
+        320 - 100
+    );
+}
+
+#[test]
+fn batteries_count_through_x_rays() {
+    // The Second Rook Backs Up the First, so the Knight Is Won Despite Its Defender
+    let fen = "3rk3/8/8/3n4/8/8/3R4/3RK3 w - - 0 1";
+
+    assert_eq!(see(fen, "d2d5"), 320);
+
+    // Without the Back Rook the Exchange Loses the Exchange
+    let fen = "3rk3/8/8/3n4/8/8/3R4/4K3 w - - 0 1";
+
+    assert_eq!(see(fen, "d2d5"), 320 - 500);
+}
+
+#[test]
+fn quiet_moves_onto_attacked_squares_lose_the_piece() {
+    assert_eq!(see("4k3/8/8/2p5/8/3Q4/8/4K3 w - - 0 1", "d3d4"), -900);
+    assert_eq!(see("4k3/8/8/8/8/3Q4/8/4K3 w - - 0 1", "d3d4"), 0);
+}
+
+#[test]
+fn king_only_recaptures_undefended_pieces() {
+    // The King Cannot Take Back While the Queen Is Defended
+    assert_eq!(see("8/8/8/8/8/5k2/4p3/3QK3 w - - 0 1", "d1e2"), 100);
+    assert_eq!(see("8/8/8/8/8/5k2/4p3/3Q3K w - - 0 1", "d1e2"), 100 - 900);
+}
+
+#[test]
+fn flags_hanging_pieces() {
+    let state = State::from_fen("4k3/8/8/q7/4p3/2N5/8/R3K3 w - - 0 1").unwrap();
+
+    assert_eq!(state.hanging_pieces(false), vec![Square::E4, Square::A5]);
+    assert_eq!(state.hanging_pieces(true), vec![Square::A1, Square::C3]);
+}
+
+#[test]
+fn quiescence_sees_past_the_horizon() {
+    // At Depth 1 a Plain Search Would Grab the Pawn and Lose the Queen to the Recapture
+    let state = State::from_fen("4k3/8/4p3/3p4/8/8/8/3QK3 w - - 0 1").unwrap();
+    let result = state.search(limits(1));
+
+    assert_ne!(result.best_move.unwrap().destination, Square::D5);
+    assert!(result.score > 500);
+}
+
+#[test]
+fn quiescence_stops_at_the_depth_limit_during_a_perpetual_check() {
+    // A Queen and Rook Down, White Can Only Check Forever With Qh5+ and Qe8+
+    let state = State::from_fen("7k/6p1/8/8/8/8/qr3PPP/3Q2K1 w - - 0 1").unwrap();
+
+    // The Full Default Depth, so Only the Clock Ends the Search
+    let result = state.search(SearchLimits {
+        time: Duration::from_secs(2),
+        ..SearchLimits::default()
+    });
+
+    let mut after = state.clone();
+    after.make_move(result.best_move.unwrap());
+
+    assert!(after.is_in_check(false));
+    assert_eq!(result.score, 0);
+}
@@ -0,0 +1,29 @@
+use chess_engine::square::{Square, SquareError};
+
+#[test]
+fn parses_and_prints_algebraic_names() {
+    for square in Square::all() {
+        assert_eq!(square.to_string().parse::<Square>(), Ok(square));
+    }
+
+    assert_eq!("e4".parse::<Square>(), Ok(Square::E4));
+    assert_eq!(Square::E4.file(), 5);
+    assert_eq!(Square::E4.rank(), 4);
+    assert_eq!(Square::H8.to_string(), "h8");
+}
+
+#[test]
+fn rejects_names_off_the_board() {
+    for name in ["", "e", "i1", "a0", "a9", "E4", "e44"] {
+        assert_eq!(name.parse::<Square>(), Err(SquareError(name.to_string())));
+    }
+}
+
+#[test]
+fn cannot_be_built_off_the_board() {
+    assert_eq!(Square::new(1, 1), Some(Square::A1));
+    assert_eq!(Square::new(0, 4), None);
+    assert_eq!(Square::new(9, 4), None);
+    assert_eq!(Square::H1.offset(1, 0), None);
+    assert_eq!(Square::A1.offset(1, 2), Some(Square::B3));
+}
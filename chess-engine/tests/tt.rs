@@ -0,0 +1,154 @@
+mod common;
+
+use chess_engine::difficulty::Difficulty;
+use chess_engine::search::{MATE, MATE_BOUND};
+use chess_engine::square::Square;
+use chess_engine::state::State;
+use chess_engine::tt::{score_from_table, score_to_table, Bound, TranspositionTable};
+use common::{limits, play};
+
+#[test]
+fn table_size_follows_megabytes() {
+    let small = TranspositionTable::new(1);
+    let large = TranspositionTable::new(4);
+
+    assert!(small.capacity() > 0);
+    assert_eq!(large.capacity() / small.capacity(), 4);
+}
+
+#[test]
+fn stores_and_probes_by_hash() {
+    let state = State::new(false);
+    let mv = state.parse_uci("e2e4").unwrap();
+
+    let mut table = TranspositionTable::new(1);
+
+    assert_eq!(table.probe(state.hash), None);
+
+    table.store(state.hash, Some(mv), 35, 4, Bound::Exact);
+
+    let entry = table.probe(state.hash).unwrap();
+
+    assert_eq!(entry.best_move, Some(mv));
+    assert_eq!(entry.score, 35);
+    assert_eq!(entry.depth, 4);
+    assert_eq!(entry.bound, Bound::Exact);
+
+    // Same Slot, Different Position
+    let other = state.hash + table.capacity() as u64;
+
+    assert_eq!(table.probe(other), None);
+
+    table.clear();
+
+    assert_eq!(table.probe(state.hash), None);
+}
+
+#[test]
+fn deeper_entries_survive_within_a_search() {
+    let mut table = TranspositionTable::new(1);
+    let key = 12345;
+    let other = key + table.capacity() as u64;
+
+    table.store(key, None, 10, 6, Bound::Lower);
+    table.store(other, None, 20, 2, Bound::Exact);
+
+    assert_eq!(table.probe(key).unwrap().depth, 6);
+    assert_eq!(table.probe(other), None);
+
+    // Entries From an Earlier Search Give Way to Anything New
+    table.new_search();
+    table.store(other, None, 20, 2, Bound::Exact);
+
+    assert_eq!(table.probe(key), None);
+    assert_eq!(table.probe(other).unwrap().score, 20);
+}
+
+#[test]
+fn fail_low_keeps_the_previous_move() {
+    let state = State::new(false);
+    let mv = state.parse_uci("d2d4").unwrap();
+
+    let mut table = TranspositionTable::new(1);
+
+    table.store(state.hash, Some(mv), 10, 3, Bound::Exact);
+    table.store(state.hash, None, -5, 4, Bound::Upper);
+
+    let entry = table.probe(state.hash).unwrap();
+
+    assert_eq!(entry.best_move, Some(mv));
+    assert_eq!(entry.bound, Bound::Upper);
+}
+
+#[test]
+fn mate_scores_are_stored_relative_to_the_node() {
+    // Mate in 3 Plies From the Root, Seen at Ply 2, Is Mate in 1 Ply From There
+    let score = MATE - 3;
+
+    assert_eq!(score_to_table(score, 2), MATE - 1);
+    assert_eq!(score_from_table(MATE - 1, 5), MATE - 6);
+    assert_eq!(score_to_table(-score, 2), -(MATE - 1));
+    assert_eq!(score_to_table(150, 7), 150);
+    assert!(score_from_table(MATE - 1, 5) >= MATE_BOUND);
+}
+
+#[test]
+fn reused_table_saves_work() {
+    let state = State::from_fen("r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq - 2 3")
+        .unwrap();
+
+    let mut table = TranspositionTable::new(4);
+
+    let first = state.search_with_table(limits(4), &mut table);
+    let second = state.search_with_table(limits(4), &mut table);
+
+    assert!(first.best_move.is_some());
+    assert_eq!(second.best_move, first.best_move);
+    assert!(second.nodes < first.nodes);
+}
+
+#[test]
+fn table_keeps_finding_mates() {
+    let state = State::from_fen("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1").unwrap();
+    let mut table = TranspositionTable::new(1);
+
+    for _ in 0..2 {
+        let result = state.search_with_table(limits(4), &mut table);
+        let mv = result.best_move.unwrap();
+
+        assert_eq!(mv.destination, Square::A8);
+        assert_eq!(result.score, MATE - 1);
+    }
+}
+
+#[test]
+fn computer_players_keep_their_table_between_moves() {
+    let mut state = State::with_table_size(true, 1);
+
+    assert!(state.white.table.is_none());
+    assert_eq!(
+        state
+            .black
+            .table
+            .as_ref()
+            .unwrap()
+            .lock()
+            .unwrap()
+            .capacity(),
+        TranspositionTable::new(1).capacity()
+    );
+
+    state.black.difficulty = Difficulty::Intermediate;
+
+    play(&mut state, &["e2e4"]);
+
+    let copy = state.clone();
+    let mv = state.black.choose_move(&state).unwrap();
+
+    // Copies of the Player Share One Table, Which Now Knows the Position Just Searched
+    let table = copy.black.table.as_ref().unwrap().lock().unwrap();
+
+    assert_eq!(table.capacity(), TranspositionTable::new(1).capacity());
+    assert!(table.probe(state.hash).is_some());
+    assert!(state.legal_moves().contains(&mv));
+}
@@ -0,0 +1,54 @@
+use chess_engine::moves::MoveError;
+use chess_engine::square::Square;
+use chess_engine::state::State;
+
+#[test]
+fn rejects_out_of_turn_moves() {
+    let mut state = State::new(false);
+    let black = state.black.clone();
+
+    assert_eq!(
+        black
+            .move_piece(&mut state, Some(Square::E7), Some(Square::E5), None)
+            .err(),
+        Some(MoveError::OutOfTurn)
+    );
+    assert_eq!(
+        state.find_move(Square::E7, Square::E5, None).err(),
+        Some(MoveError::OutOfTurn)
+    );
+
+    let white = state.white.clone();
+
+    assert!(white
+        .move_piece(&mut state, Some(Square::E2), Some(Square::E4), None)
+        .is_ok());
+    assert_eq!(
+        white
+            .move_piece(&mut state, Some(Square::D2), Some(Square::D4), None)
+            .err(),
+        Some(MoveError::OutOfTurn)
+    );
+}
+
+#[test]
+fn advances_fullmove_number_after_black() {
+    let mut state = State::new(false);
+
+    for (current_coords, destination) in [
+        (Square::G1, Square::F3),
+        (Square::G8, Square::F6),
+        (Square::F3, Square::G1),
+    ] {
+        let mv = state.find_move(current_coords, destination, None).unwrap();
+        state.apply(mv).unwrap();
+    }
+
+    assert!(!state.first_player_turn);
+    assert_eq!(state.fullmove_number, 2);
+
+    state.unmake_move();
+
+    assert!(state.first_player_turn);
+    assert_eq!(state.fullmove_number, 2);
+}
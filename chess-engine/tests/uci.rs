@@ -0,0 +1,60 @@
+mod common;
+
+use chess_engine::moves::{MoveError, MoveKind};
+use chess_engine::piece::PieceType;
+use chess_engine::square::Square;
+use chess_engine::state::State;
+use common::play;
+
+#[test]
+fn parses_legal_uci_moves() {
+    let state = State::new(false);
+    let mv = state.parse_uci("e2e4").unwrap();
+
+    assert_eq!(mv.current_coords, Square::E2);
+    assert_eq!(mv.destination, Square::E4);
+    assert_eq!(mv.kind, MoveKind::DoublePawnPush);
+    assert_eq!(mv.to_string(), "e2e4");
+
+    let state = State::from_fen("7k/4P3/8/8/8/8/8/K7 w - - 0 1").unwrap();
+    let mv = state.parse_uci("e7e8n").unwrap();
+
+    assert_eq!(mv.promotion, Some(PieceType::Knight));
+    assert_eq!(mv.to_string(), "e7e8n");
+}
+
+#[test]
+fn rejects_malformed_and_illegal_uci() {
+    let mut state = State::new(false);
+
+    assert_eq!(state.parse_uci("e2"), Err(MoveError::Malformed));
+    assert_eq!(state.parse_uci("e2e9"), Err(MoveError::Malformed));
+    assert_eq!(state.parse_uci("e2e4e5"), Err(MoveError::Malformed));
+    assert_eq!(
+        state.parse_uci("e2e5"),
+        Err(MoveError::Illegal(Square::E2, Square::E5))
+    );
+    assert_eq!(state.parse_uci("e2e4q"), Err(MoveError::InvalidPromotion));
+    assert_eq!(state.parse_uci("e7e5"), Err(MoveError::OutOfTurn));
+
+    play(&mut state, &["e2e4"]);
+
+    assert!(state.parse_uci("e7e5").is_ok());
+}
+
+#[test]
+fn requires_the_promotion_piece() {
+    let state = State::from_fen("7k/4P3/8/8/8/8/8/K7 w - - 0 1").unwrap();
+
+    assert_eq!(state.parse_uci("e7e8"), Err(MoveError::InvalidPromotion));
+    assert_eq!(state.parse_uci("e7e8k"), Err(MoveError::InvalidPromotion));
+}
+
+#[test]
+fn reports_finished_games() {
+    let mut state = State::new(false);
+
+    play(&mut state, &["f2f3", "e7e5", "g2g4", "d8h4"]);
+
+    assert_eq!(state.parse_uci("a2a3"), Err(MoveError::GameOver));
+}
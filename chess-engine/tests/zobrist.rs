@@ -0,0 +1,83 @@
+mod common;
+
+use chess_engine::moves::MoveError;
+use chess_engine::outcome::GameOutcome;
+use chess_engine::state::State;
+use common::play;
+
+fn assert_incremental(state: &mut State, depth: u32) {
+    assert_eq!(state.hash, state.compute_hash(), "{}", state.to_fen());
+
+    if depth == 0 {
+        return;
+    }
+
+    for mv in state.legal_moves() {
+        let hash = state.hash;
+
+        state.make_move(mv);
+        assert_incremental(state, depth - 1);
+        state.unmake_move();
+
+        assert_eq!(state.hash, hash);
+    }
+}
+
+#[test]
+fn incremental_hash_matches_full_recompute() {
+    let fens = [
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+        "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+    ];
+
+    for fen in fens {
+        assert_incremental(&mut State::from_fen(fen).unwrap(), 2);
+    }
+}
+
+#[test]
+fn transpositions_share_a_hash() {
+    let mut a = State::new(false);
+    let mut b = State::new(false);
+
+    play(&mut a, &["g1f3", "g8f6", "b1c3"]);
+    play(&mut b, &["b1c3", "g8f6", "g1f3"]);
+
+    assert_eq!(a.hash, b.hash);
+    assert_ne!(a.hash, State::new(false).hash);
+}
+
+#[test]
+fn unreachable_en_passant_does_not_change_the_hash() {
+    let a = State::from_fen("4k3/8/8/8/4P3/8/8/4K3 b - e3 0 1").unwrap();
+    let b = State::from_fen("4k3/8/8/8/4P3/8/8/4K3 b - - 0 1").unwrap();
+    let c = State::from_fen("4k3/8/8/8/3pP3/8/8/4K3 b - e3 0 1").unwrap();
+    let d = State::from_fen("4k3/8/8/8/3pP3/8/8/4K3 b - - 0 1").unwrap();
+
+    assert_eq!(a.hash, b.hash);
+    assert_ne!(c.hash, d.hash);
+}
+
+#[test]
+fn threefold_is_claimable_and_fivefold_is_automatic() {
+    let mut state = State::new(false);
+    let shuffle = ["g1f3", "g8f6", "f3g1", "f6g8"];
+
+    assert_eq!(state.claim_draw().err(), Some(MoveError::NoDrawToClaim));
+
+    play(&mut state, &shuffle);
+    play(&mut state, &shuffle);
+
+    assert_eq!(state.repetition_count(), 3);
+    assert_eq!(state.outcome, None);
+    assert_eq!(
+        state.clone().claim_draw(),
+        Ok(GameOutcome::ThreefoldRepetition)
+    );
+
+    play(&mut state, &shuffle);
+    play(&mut state, &shuffle);
+
+    assert_eq!(state.outcome, Some(GameOutcome::FivefoldRepetition));
+}